@@ -0,0 +1,73 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+/// Mints whose creation time is remembered; the oldest entries are evicted first.
+const CAPACITY: usize = 50_000;
+
+/// Token creation times by mint. They never change, so entries only leave on eviction.
+#[derive(Clone)]
+pub struct CreationTimes {
+    inner: Arc<Mutex<Entries>>,
+}
+
+struct Entries {
+    capacity: usize,
+    by_mint: HashMap<String, i64>,
+    order: VecDeque<String>,
+}
+
+impl Default for CreationTimes {
+    fn default() -> Self {
+        Self::with_capacity(CAPACITY)
+    }
+}
+
+impl CreationTimes {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Entries {
+                capacity,
+                by_mint: HashMap::new(),
+                order: VecDeque::new(),
+            })),
+        }
+    }
+
+    pub fn get(&self, mint: &str) -> Option<i64> {
+        let inner = self.inner.lock().expect("creation times poisoned");
+        inner.by_mint.get(mint).copied()
+    }
+
+    pub fn insert(&self, mint: &str, created_at: i64) {
+        let mut inner = self.inner.lock().expect("creation times poisoned");
+        if inner.by_mint.insert(mint.to_string(), created_at).is_some() {
+            return;
+        }
+        inner.order.push_back(mint.to_string());
+        while inner.order.len() > inner.capacity {
+            if let Some(oldest) = inner.order.pop_front() {
+                inner.by_mint.remove(&oldest);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CreationTimes;
+
+    #[test]
+    fn evicts_oldest_mint_beyond_capacity() {
+        let times = CreationTimes::with_capacity(2);
+        times.insert("a", 1);
+        times.insert("b", 2);
+        times.insert("a", 1);
+        times.insert("c", 3);
+
+        assert_eq!(None, times.get("a"));
+        assert_eq!(Some(2), times.get("b"));
+        assert_eq!(Some(3), times.get("c"));
+    }
+}
@@ -1,8 +1,33 @@
 use crate::{
-    config::{AppConfig, MarketCapBounds},
-    helius::HeliusWebhook,
+    age::CreationTimes,
+    config::{AlertUpdateSettings, AppConfig, PerformanceSettings, RuleSettings},
+    cooldown::AlertCooldowns,
+    dedup::SignatureCache,
+    filter::{EventContext, FilterPipeline, TradeSize, all_passed},
+    growth::PriceHistory,
+    health::Readiness,
+    helius::{HeliusWebhook, Trade},
+    latency::LatencyTrace,
+    launch::{self, Launch, LaunchKind},
+    metrics::Metrics,
     moralis::{MoralisClient, MoralisError, TokenMetrics},
+    performance::{FollowUp, PerformanceTracker},
+    profiles::{Activation, ProfileSelector, ProfileSet, UnknownProfile, UtcClock},
+    pumpfun::{self, BondingCurves, CurveActivity, CurveUpdate},
+    risk,
+    rpc::RpcClient,
     telegram::{TelegramClient, TelegramError},
+    updates::{AlertEditor, LiveAlert},
+    volume::{Spike, TradeVolumes},
+    watchlist::{MintLists, MintStatus, WalletActivity, WalletRole, Watchlist},
+};
+use futures_util::future::join_all;
+use std::{
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
 };
 use thiserror::Error;
 use tracing::{error, info, warn};
@@ -11,39 +36,281 @@ use tracing::{error, info, warn};
 pub struct Analyzer {
     moralis: MoralisClient,
     telegram: TelegramClient,
+    rpc: RpcClient,
     chat_id: String,
-    cap_filter: MarketCapFilter,
+    runtime: Arc<RwLock<Arc<RuntimeSettings>>>,
+    seen_signatures: SignatureCache,
+    profile_selector: ProfileSelector,
+    price_history: PriceHistory,
+    trade_volumes: TradeVolumes,
+    curves: BondingCurves,
+    cooldowns: AlertCooldowns,
+    creation_times: CreationTimes,
+    watchlist: Watchlist,
+    mints: MintLists,
+    performance: PerformanceTracker,
+    editor: AlertEditor,
+    readiness: Readiness,
+    metrics: Metrics,
+}
+
+/// Settings swapped atomically on config reload; each event sees one consistent snapshot.
+#[derive(Clone)]
+pub struct RuntimeSettings {
+    profiles: ProfileSet,
+    rules: Vec<Arc<RuleSettings>>,
+    latency_footer: bool,
+    performance: PerformanceSettings,
+    alert_updates: AlertUpdateSettings,
+}
+
+impl RuntimeSettings {
+    fn from_config(config: &AppConfig) -> Self {
+        Self {
+            profiles: ProfileSet::from_config(config),
+            rules: config.rules.iter().cloned().map(Arc::new).collect(),
+            latency_footer: config.alert_latency_footer,
+            performance: config.performance.clone(),
+            alert_updates: config.alert_updates.clone(),
+        }
+    }
+
+    /// The active profile's own rule followed by every extra rule.
+    fn rules_for(&self, profile: Arc<RuleSettings>) -> Vec<Arc<RuleSettings>> {
+        std::iter::once(profile)
+            .chain(self.rules.iter().cloned())
+            .collect()
+    }
+}
+
+/// Longest growth window of any rule; price samples older than this are useless.
+fn price_retention(config: &AppConfig) -> Duration {
+    config
+        .profiles
+        .iter()
+        .chain(&config.rules)
+        .filter_map(|profile| profile.growth.as_ref().map(|growth| growth.window))
+        .max()
+        .unwrap_or_default()
+}
+
+/// Longest volume spike window plus baseline of any rule.
+fn volume_retention(config: &AppConfig) -> Duration {
+    config
+        .profiles
+        .iter()
+        .chain(&config.rules)
+        .filter_map(|rule| {
+            rule.volume_spike
+                .as_ref()
+                .map(|spike| spike.window + spike.baseline)
+        })
+        .max()
+        .unwrap_or_default()
 }
 
 impl Analyzer {
-    pub fn new(config: &AppConfig) -> Self {
+    pub fn new(
+        config: &AppConfig,
+        watchlist: Watchlist,
+        mints: MintLists,
+        performance: PerformanceTracker,
+        readiness: Readiness,
+        metrics: Metrics,
+    ) -> Self {
+        let moralis = MoralisClient::new(
+            config.moralis_api_key.clone(),
+            config.moralis_base_url.clone(),
+        );
+        let telegram = TelegramClient::new(
+            config.telegram_bot_token.clone(),
+            config.telegram_api_base.clone(),
+        );
         Self {
-            moralis: MoralisClient::new(
-                config.moralis_api_key.clone(),
-                config.moralis_base_url.clone(),
-            ),
-            telegram: TelegramClient::new(
-                config.telegram_bot_token.clone(),
-                config.telegram_api_base.clone(),
+            editor: AlertEditor::new(
+                telegram.clone(),
+                moralis.clone(),
+                metrics.clone(),
+                config.telegram_chat_id.clone(),
             ),
+            moralis,
+            telegram,
+            rpc: RpcClient::new(&config.rpc),
             chat_id: config.telegram_chat_id.clone(),
-            cap_filter: MarketCapFilter::new(config.market_cap_bounds.clone()),
+            runtime: Arc::new(RwLock::new(Arc::new(RuntimeSettings::from_config(config)))),
+            seen_signatures: SignatureCache::new(config.dedup.capacity, config.dedup.ttl),
+            profile_selector: ProfileSelector::default(),
+            price_history: PriceHistory::new(price_retention(config)),
+            trade_volumes: TradeVolumes::new(volume_retention(config)),
+            curves: BondingCurves::default(),
+            cooldowns: AlertCooldowns::default(),
+            creation_times: CreationTimes::default(),
+            watchlist,
+            mints,
+            performance,
+            readiness,
+            metrics,
         }
     }
 
-    pub async fn process_event(&self, event: HeliusWebhook) -> Result<(), AnalyzerError> {
+    /// Replaces the reloadable settings; events already in flight keep their snapshot.
+    pub fn apply_config(&self, config: &AppConfig) {
+        let settings = Arc::new(RuntimeSettings::from_config(config));
+        *self.runtime.write().expect("runtime settings poisoned") = settings;
+        self.price_history.set_retention(price_retention(config));
+        self.trade_volumes.set_retention(volume_retention(config));
+        self.watchlist.set_configured(&config.watchlist.wallets);
+        self.mints.set_configured(&config.mints);
+    }
+
+    /// Manually activates `profile` until the schedule moves on; `None` clears the switch.
+    pub fn switch_profile(&self, profile: Option<&str>) -> Result<(), UnknownProfile> {
+        let runtime = self.runtime();
+        self.profile_selector
+            .switch(&runtime.profiles, profile, UtcClock::now())
+    }
+
+    /// Configured profiles and the one currently active.
+    pub fn profile_status(&self) -> (ProfileSet, Activation) {
+        let runtime = self.runtime();
+        let activation = self.active_profile(&runtime);
+        (runtime.profiles.clone(), activation)
+    }
+
+    fn active_profile(&self, runtime: &RuntimeSettings) -> Activation {
+        self.profile_selector
+            .resolve(&runtime.profiles, UtcClock::now())
+    }
+
+    fn runtime(&self) -> Arc<RuntimeSettings> {
+        self.runtime
+            .read()
+            .expect("runtime settings poisoned")
+            .clone()
+    }
+
+    /// Checks Telegram and Moralis once and records the outcome for `/readyz`.
+    pub async fn probe_dependencies(&self) {
+        match self.telegram.get_me().await {
+            Ok(()) => self.readiness.set_telegram(true),
+            Err(err) => {
+                self.readiness.set_telegram(false);
+                warn!(error = ?err, source = "telegram", "readiness_probe_failed");
+            }
+        }
+
+        match self.moralis.probe().await {
+            Ok(()) => self.readiness.set_price_provider(true),
+            Err(err) => {
+                self.readiness.set_price_provider(false);
+                warn!(error = ?err, source = "moralis", "readiness_probe_failed");
+            }
+        }
+    }
+
+    /// Analyzes one webhook event, skipping signatures already processed.
+    ///
+    /// A signature is claimed on arrival so concurrent redeliveries are skipped. It is
+    /// released when processing fails before any alert went out, so a later delivery of the
+    /// transaction is analyzed again; once an alert is sent, a redelivery would repeat it.
+    pub async fn process_event(
+        &self,
+        event: HeliusWebhook,
+        trace: LatencyTrace,
+    ) -> Result<(), AnalyzerError> {
         let signature = event.signature.clone();
-        let maybe_mint = event.primary_mint().map(str::to_string);
+
+        if !self.seen_signatures.insert(&signature) {
+            self.metrics.event_skipped("duplicate_signature");
+            info!(
+                signature = signature.as_str(),
+                reason = "duplicate_signature",
+                "skip_event"
+            );
+            return Ok(());
+        }
+
+        let delivered = AtomicBool::new(false);
+        let processed = self.analyze(event, trace, &delivered).await;
+        if processed.is_err() && !delivered.load(Ordering::Relaxed) {
+            self.seen_signatures.remove(&signature);
+        }
+        processed
+    }
+
+    /// Sets `delivered` as soon as any alert for the event has been sent.
+    async fn analyze(
+        &self,
+        event: HeliusWebhook,
+        trace: LatencyTrace,
+        delivered: &AtomicBool,
+    ) -> Result<(), AnalyzerError> {
+        let signature = event.signature.clone();
+
+        // A launch's token is the one worth looking at, whatever else moved.
+        let launch = launch::detect(&event);
+        let curve = pumpfun::parse(&event);
+        let maybe_mint = launch
+            .as_ref()
+            .map(|launch| launch.base_mint.clone())
+            .or_else(|| curve.as_ref().map(|curve| curve.mint().to_string()))
+            .or_else(|| event.primary_mint().map(str::to_string));
+
+        if let Some(lag) = trace.chain_to_intake() {
+            self.metrics.observe_chain_to_intake(lag);
+        }
 
         info!(
             signature = signature.as_str(),
             mint = maybe_mint.as_deref().unwrap_or(""),
+            slot = event.slot,
+            block_time = event.timestamp,
+            chain_to_intake_ms = trace.chain_to_intake_ms(),
             "intake_event"
         );
+        if let Some(launch) = &launch {
+            info!(
+                signature = signature.as_str(),
+                kind = launch.kind.as_str(),
+                venue = launch.venue.as_str(),
+                base_mint = launch.base_mint.as_str(),
+                quote_mint = launch.quote_mint.as_deref().unwrap_or(""),
+                pool = launch.pool.as_deref().unwrap_or(""),
+                base_tokens = launch.base_tokens,
+                quote_tokens = launch.quote_tokens,
+                "launch_detected"
+            );
+        }
+
+        // Watched wallets alert on their own, without waiting for market data.
+        let (watched, rules) = tokio::join!(
+            self.alert_watched_wallets(&event, trace, delivered),
+            self.evaluate_rules(
+                &event,
+                maybe_mint,
+                launch.as_ref(),
+                curve.as_ref(),
+                trace,
+                delivered
+            ),
+        );
+        watched.and(rules)
+    }
 
+    async fn evaluate_rules(
+        &self,
+        event: &HeliusWebhook,
+        maybe_mint: Option<String>,
+        launch: Option<&Launch>,
+        curve: Option<&CurveActivity>,
+        mut trace: LatencyTrace,
+        delivered: &AtomicBool,
+    ) -> Result<(), AnalyzerError> {
+        let signature = event.signature.clone();
         let mint = match maybe_mint {
             Some(mint) => mint,
             None => {
+                self.metrics.event_skipped("missing_mint");
                 warn!(
                     signature = signature.as_str(),
                     reason = "missing_mint",
@@ -53,9 +320,171 @@ impl Analyzer {
             }
         };
 
-        let metrics = match self.moralis.fetch_token_metrics(&mint).await {
-            Ok(metrics) => metrics,
+        let mint_status = self.mints.status(&mint);
+        if mint_status == MintStatus::Ignored {
+            self.metrics.event_skipped("blacklisted_mint");
+            info!(
+                signature = signature.as_str(),
+                mint = mint.as_str(),
+                reason = "blacklisted_mint",
+                "skip_event"
+            );
+            return Ok(());
+        }
+
+        // Every swap counts towards volume, even when no rule goes on to evaluate it.
+        if let Some(amount) = event.swap_amount(&mint) {
+            self.trade_volumes.record(&mint, amount);
+        }
+        // Likewise every curve trade moves the fill, so crossings are seen in order.
+        let curve = curve
+            .filter(|curve| curve.mint() == mint)
+            .map(|curve| self.record_curve(&signature, curve));
+
+        let runtime = self.runtime();
+        let profile = self.active_profile(&runtime).profile;
+
+        // A watched mint is looked at through every rule whatever the event; only
+        // cooldowns still apply, so a busy mint does not flood the chat.
+        let watched = mint_status == MintStatus::Watched;
+        let mut eligible = Vec::new();
+        let mut skip_reason = "event_type_filtered";
+        for rule in runtime.rules_for(profile) {
+            if !watched && !rule.accepts_event_type(event.event_type.as_deref()) {
+                info!(
+                    signature = signature.as_str(),
+                    mint = mint.as_str(),
+                    rule = rule.name.as_str(),
+                    event_type = event.event_type.as_deref().unwrap_or(""),
+                    reason = "event_type_filtered",
+                    "skip_rule"
+                );
+                continue;
+            }
+            if !watched && rule.launches_only && launch.is_none() {
+                skip_reason = "not_a_launch";
+                info!(
+                    signature = signature.as_str(),
+                    mint = mint.as_str(),
+                    rule = rule.name.as_str(),
+                    reason = "not_a_launch",
+                    "skip_rule"
+                );
+                continue;
+            }
+            if let Some(remaining) = self.cooldowns.remaining(&cooldown_key(&rule, &mint)) {
+                skip_reason = "cooldown";
+                info!(
+                    signature = signature.as_str(),
+                    mint = mint.as_str(),
+                    rule = rule.name.as_str(),
+                    cooldown_remaining_secs = remaining.as_secs(),
+                    reason = "cooldown",
+                    "skip_rule"
+                );
+                continue;
+            }
+            eligible.push(rule);
+        }
+
+        if eligible.is_empty() {
+            self.metrics.event_skipped(skip_reason);
+            info!(
+                signature = signature.as_str(),
+                mint = mint.as_str(),
+                reason = skip_reason,
+                "skip_event"
+            );
+            return Ok(());
+        }
+
+        let need_pair_stats = eligible.iter().any(|rule| rule.needs_pair_stats());
+        let need_holders = eligible.iter().any(|rule| rule.needs_holders());
+        let cached_creation = self.creation_times.get(&mint);
+        let need_creation_time =
+            cached_creation.is_none() && eligible.iter().any(|rule| rule.needs_creation_time());
+        let need_risk = eligible.iter().any(|rule| rule.needs_risk());
+        let started = Instant::now();
+        let (fetched, pair_stats, holders, creation_time, risk_report, quote_price) = tokio::join!(
+            self.moralis.fetch_token_metrics(&mint),
+            async {
+                if need_pair_stats {
+                    Some(self.moralis.fetch_pair_stats(&mint).await)
+                } else {
+                    None
+                }
+            },
+            async {
+                if need_holders {
+                    Some(self.moralis.fetch_holder_count(&mint).await)
+                } else {
+                    None
+                }
+            },
+            async {
+                if need_creation_time {
+                    Some(self.moralis.fetch_creation_time(&mint).await)
+                } else {
+                    None
+                }
+            },
+            async {
+                if !need_risk {
+                    return None;
+                }
+                let started = Instant::now();
+                let report = risk::assess(&self.rpc, &mint).await;
+                self.metrics
+                    .observe_provider_latency("rpc", started.elapsed());
+                Some(report)
+            },
+            async {
+                let launch = launch?;
+                if let Some(price) = launch.stable_quote_price() {
+                    return Some(price);
+                }
+                let quote_mint = launch.quote_mint.as_deref()?;
+                match self.moralis.fetch_token_metrics(quote_mint).await {
+                    Ok(quote) => quote.price_usd,
+                    Err(err) => {
+                        self.log_optional_lookup_error(&signature, &mint, "quote_price", &err);
+                        None
+                    }
+                }
+            },
+        );
+        self.metrics
+            .observe_provider_latency("moralis", started.elapsed());
+
+        let mut metrics = match fetched {
+            Ok(metrics) => {
+                self.readiness.set_price_provider(true);
+                metrics
+            }
+            // Moralis has not indexed a token launched moments ago; the launch itself
+            // supplies the price and liquidity below, and a watched mint alerts without.
+            Err(MoralisError::HttpStatus(status))
+                if (launch.is_some() || watched) && status == reqwest::StatusCode::NOT_FOUND =>
+            {
+                self.metrics
+                    .provider_error("moralis", &status.as_u16().to_string());
+                warn!(
+                    signature = signature.as_str(),
+                    mint = mint.as_str(),
+                    status = status.as_u16(),
+                    source = "moralis",
+                    lookup = "price",
+                    "external_api_error"
+                );
+                TokenMetrics::default()
+            }
             Err(err) => {
+                if matches!(&err, MoralisError::Transport(_))
+                    || matches!(&err, MoralisError::HttpStatus(status) if status.is_server_error())
+                {
+                    self.readiness.set_price_provider(false);
+                }
+                self.metrics.provider_error("moralis", &err.status_label());
                 error!(
                     signature = signature.as_str(),
                     mint = mint.as_str(),
@@ -67,9 +496,74 @@ impl Analyzer {
             }
         };
 
+        // Without pair or holder data the rules that need it fail with `missing_*`.
+        match pair_stats {
+            Some(Ok(stats)) => metrics.apply_pair_stats(stats),
+            Some(Err(err)) => self.log_optional_lookup_error(&signature, &mint, "pairs", &err),
+            None => {}
+        }
+        match holders {
+            Some(Ok(holders)) => metrics.holders = holders,
+            Some(Err(err)) => self.log_optional_lookup_error(&signature, &mint, "holders", &err),
+            None => {}
+        }
+        metrics.created_at = match creation_time {
+            Some(Ok(created_at)) => {
+                if let Some(created_at) = created_at {
+                    self.creation_times.insert(&mint, created_at);
+                }
+                created_at
+            }
+            Some(Err(err)) => {
+                self.log_optional_lookup_error(&signature, &mint, "metadata", &err);
+                None
+            }
+            None => cached_creation,
+        };
+        // A failed or non-mint lookup leaves the risk rules to fail with `missing_risk_data`.
+        let risk_report = match risk_report {
+            Some(Ok(report)) => report,
+            Some(Err(err)) => {
+                self.metrics.provider_error("rpc", &err.status_label());
+                warn!(
+                    signature = signature.as_str(),
+                    mint = mint.as_str(),
+                    error = ?err,
+                    source = "rpc",
+                    lookup = "risk",
+                    "external_api_error"
+                );
+                None
+            }
+            None => None,
+        };
+
+        if let (Some(launch), Some(quote_price)) = (launch, quote_price) {
+            if metrics.liquidity_usd.is_none() {
+                metrics.liquidity_usd = launch.initial_liquidity_usd(quote_price);
+            }
+            if metrics.price_usd.is_none() {
+                metrics.price_usd = launch.initial_price_usd(quote_price);
+            }
+        }
+
+        if let Some(price) = metrics.price_usd {
+            self.price_history.record(&mint, price);
+        }
+
         let market_cap = match metrics.market_cap_usd {
-            Some(cap) => cap,
+            Some(cap) => Some((cap, MarketCapSource::Moralis)),
+            None => self
+                .market_cap_from_supply(&signature, &mint, metrics.price_usd)
+                .await
+                .map(|cap| (cap, MarketCapSource::Supply)),
+        };
+        let (market_cap, market_cap_source) = match market_cap {
+            Some(found) => found,
+            // Watched mints skip the filters, so nothing needs the cap.
+            None if watched => (0.0, MarketCapSource::Unknown),
             None => {
+                self.metrics.event_skipped("missing_market_cap");
                 warn!(
                     signature = signature.as_str(),
                     mint = mint.as_str(),
@@ -80,54 +574,145 @@ impl Analyzer {
             }
         };
 
-        match self.cap_filter.evaluate(market_cap) {
-            FilterOutcome::Pass => {
-                info!(
-                    signature = signature.as_str(),
-                    mint = mint.as_str(),
-                    market_cap_usd = market_cap,
-                    decision = "pass",
-                    reason = "within_range",
-                    "market_cap_filter_decision"
-                );
-                self.send_alert(&mint, &signature, &metrics, market_cap)
-                    .await?;
-            }
-            FilterOutcome::Fail { reason } => {
-                info!(
-                    signature = signature.as_str(),
-                    mint = mint.as_str(),
-                    market_cap_usd = market_cap,
-                    decision = "fail",
-                    reason = reason,
-                    "market_cap_filter_decision"
+        trace.mark_decision();
+        let trade = event.trade(&mint);
+        let context = EventContext {
+            mint: &mint,
+            market_cap,
+            metrics: &metrics,
+            prices: &self.price_history,
+            volumes: &self.trade_volumes,
+            trade: trade.as_ref(),
+            curve: curve.as_ref(),
+            risk: risk_report.as_ref(),
+        };
+        let matches: Vec<RuleMatch> = eligible
+            .into_iter()
+            .filter_map(|rule| self.evaluate_rule(rule, watched, &signature, &context, &trace))
+            .collect();
+        if matches.is_empty() {
+            return Ok(());
+        }
+
+        // Every matching rule alerts on its own; deliveries run concurrently.
+        let deliveries = matches.iter().map(|matched| {
+            let alert = AlertContext {
+                mint: &mint,
+                signature: &signature,
+                metrics: &metrics,
+                market_cap,
+                market_cap_source,
+                trade: trade.as_ref(),
+                launch,
+                matched,
+            };
+            let trace = &trace;
+            let runtime = &runtime;
+            async move {
+                let sent = self.send_alert(&alert, trace, runtime).await?;
+                self.cooldowns.record(
+                    &cooldown_key(&matched.rule, alert.mint),
+                    matched.rule.cooldown,
                 );
+                self.metrics
+                    .observe_webhook_to_alert(trace.received_at().elapsed());
+                self.track_performance(&alert, &runtime.performance);
+                self.keep_updated(&alert, sent, &runtime.alert_updates);
+                Ok::<(), AnalyzerError>(())
             }
+        });
+        let results = join_all(deliveries).await;
+        if results.iter().any(Result::is_ok) {
+            delivered.store(true, Ordering::Relaxed);
         }
+        results.into_iter().collect::<Result<Vec<()>, _>>()?;
 
         Ok(())
     }
 
-    async fn send_alert(
+    /// Sends one alert per watched wallet in the transaction; no rule filter applies.
+    async fn alert_watched_wallets(
         &self,
-        mint: &str,
-        signature: &str,
-        metrics: &TokenMetrics,
-        market_cap: f64,
+        event: &HeliusWebhook,
+        mut trace: LatencyTrace,
+        delivered: &AtomicBool,
     ) -> Result<(), AnalyzerError> {
-        let price_line = metrics
-            .price_usd
-            .map(|p| format!("{p:.6}"))
-            .unwrap_or_else(|| "n/a".to_string());
+        let mut activity = self.watchlist.activity(event);
+        activity.retain(|entry| {
+            entry
+                .mint
+                .as_deref()
+                .is_none_or(|mint| self.mints.status(mint) != MintStatus::Ignored)
+        });
+        if activity.is_empty() {
+            return Ok(());
+        }
+        trace.mark_decision();
 
-        let message = format!(
-            "Solana token alert\nMint: {mint}\nSignature: {signature}\nMarket cap (USD): {market_cap:.2}\nPrice (USD): {price_line}"
-        );
+        let mut wallets: Vec<&str> = Vec::new();
+        for entry in &activity {
+            info!(
+                signature = event.signature.as_str(),
+                mint = entry.mint.as_deref().unwrap_or(""),
+                wallet = entry.wallet.as_str(),
+                role = entry.role.as_str(),
+                tokens = entry.tokens,
+                "watched_wallet_activity"
+            );
+            if !wallets.contains(&entry.wallet.as_str()) {
+                wallets.push(&entry.wallet);
+            }
+        }
+        let runtime = self.runtime();
+        let deliveries = wallets
+            .into_iter()
+            .map(|wallet| self.send_wallet_alert(event, wallet, &activity, &trace, &runtime));
+        let results = join_all(deliveries).await;
+        if results.iter().any(Result::is_ok) {
+            delivered.store(true, Ordering::Relaxed);
+        }
+        results.into_iter().collect::<Result<Vec<()>, _>>()?;
+        Ok(())
+    }
 
-        if let Err(err) = self.telegram.send_message(&self.chat_id, &message).await {
+    async fn send_wallet_alert(
+        &self,
+        event: &HeliusWebhook,
+        wallet: &str,
+        activity: &[WalletActivity],
+        trace: &LatencyTrace,
+        runtime: &RuntimeSettings,
+    ) -> Result<(), AnalyzerError> {
+        let signature = event.signature.as_str();
+        let activity: Vec<&WalletActivity> = activity
+            .iter()
+            .filter(|entry| entry.wallet == wallet)
+            .collect();
+        let mint = activity
+            .iter()
+            .find_map(|entry| entry.mint.as_deref())
+            .unwrap_or("");
+        let mut message = format!("Watched wallet alert\nWallet: {wallet}");
+        for entry in &activity {
+            message.push('\n');
+            message.push_str(&describe_activity(entry));
+        }
+        if let Some(event_type) = &event.event_type {
+            message.push_str(&format!("\nType: {event_type}"));
+        }
+        message.push_str(&format!("\nSignature: {signature}"));
+        if runtime.latency_footer {
+            message.push('\n');
+            message.push_str(&trace.footer());
+        }
+
+        let (delivered, delivered_at) = self.deliver(&message).await;
+        if let Err(err) = delivered {
             error!(
                 signature = signature,
                 mint = mint,
+                wallet = wallet,
+                rule = WATCHLIST_RULE,
                 error = ?err,
                 source = "telegram",
                 "alert_delivery_failed"
@@ -135,45 +720,539 @@ impl Analyzer {
             return Err(err.into());
         }
 
+        self.metrics.alert_sent();
+        self.metrics
+            .observe_webhook_to_alert(trace.received_at().elapsed());
+        info!(
+            signature = signature,
+            mint = mint,
+            wallet = wallet,
+            rule = WATCHLIST_RULE,
+            chain_to_intake_ms = trace.chain_to_intake_ms(),
+            intake_to_decision_ms = trace.intake_to_decision_ms(),
+            decision_to_delivery_ms = trace.decision_to_delivery_ms(delivered_at),
+            "alert_sent"
+        );
         Ok(())
     }
-}
 
-#[derive(Clone)]
-pub struct MarketCapFilter {
-    bounds: MarketCapBounds,
-}
+    fn record_curve(&self, signature: &str, activity: &CurveActivity) -> CurveUpdate {
+        let update = self.curves.record(activity);
+        match (activity, update) {
+            (
+                CurveActivity::Trade(trade),
+                CurveUpdate::Progress {
+                    fill_percent,
+                    previous_percent,
+                },
+            ) => info!(
+                signature = signature,
+                mint = trade.mint.as_str(),
+                side = if trade.is_buy { "buy" } else { "sell" },
+                sol = trade.sol,
+                tokens = trade.tokens,
+                fill_percent = fill_percent,
+                previous_percent = previous_percent,
+                "curve_progress"
+            ),
+            (
+                _,
+                CurveUpdate::Migrated {
+                    destination,
+                    last_percent,
+                },
+            ) => info!(
+                signature = signature,
+                mint = activity.mint(),
+                destination = destination,
+                last_percent = last_percent,
+                "curve_migrated"
+            ),
+            _ => {}
+        }
+        update
+    }
 
-impl MarketCapFilter {
-    pub fn new(bounds: MarketCapBounds) -> Self {
-        Self { bounds }
+    /// Schedules follow-up price checks for a sent alert; alerts without a price are not tracked.
+    fn track_performance(&self, alert: &AlertContext<'_>, settings: &PerformanceSettings) {
+        let Some(price_usd) = alert.metrics.price_usd.filter(|price| *price > 0.0) else {
+            return;
+        };
+        if settings.offsets.is_empty() {
+            return;
+        }
+        let follow_up = FollowUp {
+            rule: alert.matched.rule.name.clone(),
+            mint: alert.mint.to_string(),
+            signature: alert.signature.to_string(),
+            price_usd,
+            sent_at: Instant::now(),
+        };
+        let started = self.performance.spawn(
+            self.moralis.clone(),
+            self.metrics.clone(),
+            follow_up,
+            settings.clone(),
+        );
+        if !started {
+            warn!(
+                signature = alert.signature,
+                mint = alert.mint,
+                rule = alert.matched.rule.name.as_str(),
+                "follow_up_dropped"
+            );
+        }
+    }
+
+    /// Edits a sent alert with the latest price for a while; alerts without a price stay as sent.
+    fn keep_updated(
+        &self,
+        alert: &AlertContext<'_>,
+        sent: SentAlert,
+        settings: &AlertUpdateSettings,
+    ) {
+        let Some(price_usd) = alert.metrics.price_usd.filter(|price| *price > 0.0) else {
+            return;
+        };
+        if settings.duration.is_zero() {
+            return;
+        }
+        let message_id = sent.message_id;
+        let live = LiveAlert {
+            rule: alert.matched.rule.name.clone(),
+            mint: alert.mint.to_string(),
+            signature: alert.signature.to_string(),
+            message_id,
+            text: sent.text,
+            price_usd,
+            sent_at: sent.sent_at,
+        };
+        if !self.editor.spawn(live, settings.clone()) {
+            warn!(
+                signature = alert.signature,
+                mint = alert.mint,
+                rule = alert.matched.rule.name.as_str(),
+                message_id = message_id,
+                "alert_update_dropped"
+            );
+        }
     }
 
-    pub fn evaluate(&self, market_cap: f64) -> FilterOutcome {
-        if let Some(min) = self.bounds.min {
-            if market_cap < min {
-                return FilterOutcome::Fail {
-                    reason: "out_of_cap_range",
-                };
+    /// Sends `message` to the alert chat, recording latency and failures.
+    async fn deliver(&self, message: &str) -> (Result<i64, TelegramError>, Instant) {
+        let started = Instant::now();
+        let delivered = self.telegram.send_message(&self.chat_id, message).await;
+        let delivered_at = Instant::now();
+        self.metrics
+            .observe_provider_latency("telegram", delivered_at.duration_since(started));
+        if let Err(err) = &delivered {
+            self.metrics.alert_failed();
+            self.metrics.provider_error("telegram", &err.status_label());
+        }
+        (delivered, delivered_at)
+    }
+
+    /// Market cap as on-chain total supply times the best known price, for tokens
+    /// Moralis has no market cap for yet. Falls back to the last recorded price.
+    async fn market_cap_from_supply(
+        &self,
+        signature: &str,
+        mint: &str,
+        price_usd: Option<f64>,
+    ) -> Option<f64> {
+        let price = price_usd.or_else(|| self.price_history.latest(mint))?;
+
+        let started = Instant::now();
+        let supply = self.rpc.get_token_supply(mint).await;
+        self.metrics
+            .observe_provider_latency("rpc", started.elapsed());
+        let supply = match supply {
+            Ok(supply) => supply.ui_amount(),
+            Err(err) => {
+                self.metrics.provider_error("rpc", &err.status_label());
+                warn!(
+                    signature = signature,
+                    mint = mint,
+                    error = ?err,
+                    source = "rpc",
+                    lookup = "supply",
+                    "external_api_error"
+                );
+                return None;
+            }
+        };
+
+        let market_cap = supply * price;
+        info!(
+            signature = signature,
+            mint = mint,
+            supply = supply,
+            price_usd = price,
+            market_cap_usd = market_cap,
+            "market_cap_from_supply"
+        );
+        (market_cap.is_finite() && market_cap > 0.0).then_some(market_cap)
+    }
+
+    fn log_optional_lookup_error(
+        &self,
+        signature: &str,
+        mint: &str,
+        lookup: &'static str,
+        err: &MoralisError,
+    ) {
+        self.metrics.provider_error("moralis", &err.status_label());
+        warn!(
+            signature = signature,
+            mint = mint,
+            error = ?err,
+            source = "moralis",
+            lookup = lookup,
+            "external_api_error"
+        );
+    }
+
+    /// Runs one rule's filter pipeline and logs every stage's verdict tagged with the rule.
+    ///
+    /// A watched mint replaces the rule's filters with a single passing `mint_watchlist` stage.
+    fn evaluate_rule(
+        &self,
+        rule: Arc<RuleSettings>,
+        watched_mint: bool,
+        signature: &str,
+        event: &EventContext<'_>,
+        trace: &LatencyTrace,
+    ) -> Option<RuleMatch> {
+        let pipeline = if watched_mint {
+            FilterPipeline::watched_mint()
+        } else {
+            FilterPipeline::for_rule(&rule)
+        };
+        let verdicts = pipeline.run(event);
+        for verdict in &verdicts {
+            let outcome = &verdict.outcome;
+            let measurement = outcome.measurement();
+            self.metrics
+                .filter_decision(verdict.filter, outcome.decision(), outcome.code);
+            info!(
+                signature = signature,
+                mint = event.mint,
+                rule = rule.name.as_str(),
+                filter = verdict.filter,
+                market_cap_usd = event.market_cap,
+                decision = outcome.decision(),
+                code = outcome.code,
+                reason = outcome.reason().as_str(),
+                value = measurement.map(|m| m.value),
+                comparison = measurement.map(|m| m.comparison.symbol()),
+                bound = measurement.map(|m| m.bound),
+                chain_to_intake_ms = trace.chain_to_intake_ms(),
+                intake_to_decision_ms = trace.intake_to_decision_ms(),
+                "filter_decision"
+            );
+            // The market-cap stage keeps its original event, with the code as `reason`,
+            // for dashboards built on it.
+            if verdict.filter == "market_cap" {
+                info!(
+                    signature = signature,
+                    mint = event.mint,
+                    rule = rule.name.as_str(),
+                    market_cap_usd = event.market_cap,
+                    decision = outcome.decision(),
+                    reason = outcome.code,
+                    chain_to_intake_ms = trace.chain_to_intake_ms(),
+                    intake_to_decision_ms = trace.intake_to_decision_ms(),
+                    "market_cap_filter_decision"
+                );
             }
         }
+        if !all_passed(&verdicts) {
+            return None;
+        }
+
+        let growth_percent = rule
+            .growth
+            .as_ref()
+            .and_then(|growth| self.price_history.growth_percent(event.mint, growth.window));
+        let warnings = verdicts
+            .into_iter()
+            .flat_map(|verdict| verdict.outcome.warnings)
+            .collect();
+        let volume_spike = rule.volume_spike.as_ref().map(|spike| {
+            self.trade_volumes
+                .spike(event.mint, spike.window, spike.baseline)
+        });
+        let whale = if rule.whale.is_active() {
+            TradeSize::measure(event)
+        } else {
+            None
+        };
+        let curve = event.curve.copied().filter(|_| rule.curve.is_active());
+        Some(RuleMatch {
+            rule,
+            growth_percent,
+            volume_spike,
+            whale,
+            curve,
+            warnings,
+        })
+    }
+
+    async fn send_alert(
+        &self,
+        alert: &AlertContext<'_>,
+        trace: &LatencyTrace,
+        runtime: &RuntimeSettings,
+    ) -> Result<SentAlert, AnalyzerError> {
+        let AlertContext {
+            mint,
+            signature,
+            market_cap,
+            ..
+        } = *alert;
+        let price_line = alert
+            .metrics
+            .price_usd
+            .map(|p| format!("{p:.6}"))
+            .unwrap_or_else(|| "n/a".to_string());
 
-        if let Some(max) = self.bounds.max {
-            if market_cap > max {
-                return FilterOutcome::Fail {
-                    reason: "out_of_cap_range",
-                };
+        let title = match (
+            alert.launch,
+            alert.matched.curve,
+            alert.trade,
+            alert.matched.whale,
+        ) {
+            (Some(_), ..) => "New launch alert",
+            (None, Some(CurveUpdate::Migrated { .. }), ..) => "Migration alert",
+            (None, Some(CurveUpdate::Progress { .. }), ..) => "Bonding curve alert",
+            (None, None, Some(_), Some(_)) => "Whale trade alert",
+            _ => "Solana token alert",
+        };
+        let market_cap_line = match alert.market_cap_source {
+            MarketCapSource::Unknown => "n/a".to_string(),
+            source => format!("{market_cap:.2} ({})", source.label()),
+        };
+        let mut message = format!(
+            "{title}\nMint: {mint}\nSignature: {signature}\nMarket cap (USD): {market_cap_line}\nPrice (USD): {price_line}"
+        );
+        if let Some(launch) = alert.launch {
+            message.push_str(&describe_launch(launch));
+        }
+        if let Some(curve) = alert.matched.curve {
+            message.push_str(&describe_curve(curve));
+        }
+        if let (Some(trade), Some(size)) = (alert.trade, alert.matched.whale) {
+            message.push_str(&format!(
+                "\nWallet: {}\nDirection: {}\nSize: {:.2} tokens (~{:.2} USD)",
+                trade.wallet,
+                trade.direction.as_str().to_ascii_uppercase(),
+                trade.tokens,
+                size.usd
+            ));
+            if let Some(percent) = size.market_cap_percent {
+                message.push_str(&format!("\nShare of market cap: {percent:.2}%"));
+            }
+            if let Some(percent) = size.liquidity_percent {
+                message.push_str(&format!("\nShare of liquidity: {percent:.2}%"));
             }
         }
+        let token = alert.metrics;
+        if let Some(liquidity) = token.liquidity_usd {
+            message.push_str(&format!("\nLiquidity (USD): {liquidity:.2}"));
+        }
+        if let Some(volume) = token.volume_24h_usd {
+            message.push_str(&format!("\n24h volume (USD): {volume:.2}"));
+        }
+        if let Some(holders) = token.holders {
+            message.push_str(&format!("\nHolders: {holders}"));
+        }
+        if let Some(pair) = &token.pair_address {
+            message.push_str(&format!("\nPair: {pair}"));
+        }
+        let rule = &alert.matched.rule;
+        if let (Some(growth), Some(settings)) = (alert.matched.growth_percent, &rule.growth) {
+            message.push_str(&format!(
+                "\nGrowth: {growth:+.1}% over {}s",
+                settings.window.as_secs()
+            ));
+        }
+        if let (Some(spike), Some(settings)) = (alert.matched.volume_spike, &rule.volume_spike) {
+            let usd = token
+                .price_usd
+                .map(|price| format!(" (~{:.2} USD)", spike.recent.tokens * price))
+                .unwrap_or_default();
+            message.push_str(&format!(
+                "\nVolume spike: {:.1}x baseline, {:.2} tokens{usd} in {} swaps over {}s",
+                spike.ratio.unwrap_or_default(),
+                spike.recent.tokens,
+                spike.recent.trades,
+                settings.window.as_secs()
+            ));
+        }
+        for warning in &alert.matched.warnings {
+            message.push_str(&format!("\nRisk warning: {warning}"));
+        }
+        message.push_str(&format!("\nRule: {}", rule.name));
+        if runtime.latency_footer {
+            message.push('\n');
+            message.push_str(&trace.footer());
+        }
 
-        FilterOutcome::Pass
+        let (delivered, delivered_at) = self.deliver(&message).await;
+        let message_id = match delivered {
+            Ok(message_id) => message_id,
+            Err(err) => {
+                error!(
+                    signature = signature,
+                    mint = mint,
+                    rule = rule.name.as_str(),
+                    error = ?err,
+                    source = "telegram",
+                    chain_to_intake_ms = trace.chain_to_intake_ms(),
+                    intake_to_decision_ms = trace.intake_to_decision_ms(),
+                    decision_to_delivery_ms = trace.decision_to_delivery_ms(delivered_at),
+                    "alert_delivery_failed"
+                );
+                return Err(err.into());
+            }
+        };
+
+        self.metrics.alert_sent();
+        info!(
+            signature = signature,
+            mint = mint,
+            rule = rule.name.as_str(),
+            message_id = message_id,
+            chain_to_intake_ms = trace.chain_to_intake_ms(),
+            intake_to_decision_ms = trace.intake_to_decision_ms(),
+            decision_to_delivery_ms = trace.decision_to_delivery_ms(delivered_at),
+            "alert_sent"
+        );
+        Ok(SentAlert {
+            message_id,
+            text: message,
+            sent_at: delivered_at,
+        })
+    }
+}
+
+/// A delivered rule alert, kept for later edits.
+struct SentAlert {
+    message_id: i64,
+    text: String,
+    sent_at: Instant,
+}
+
+struct RuleMatch {
+    rule: Arc<RuleSettings>,
+    growth_percent: Option<f64>,
+    volume_spike: Option<Spike>,
+    /// Size of the triggering trade, for rules with whale thresholds.
+    whale: Option<TradeSize>,
+    /// Curve milestone, for rules with curve thresholds.
+    curve: Option<CurveUpdate>,
+    /// Non-blocking concerns raised by the rule's filters.
+    warnings: Vec<String>,
+}
+
+struct AlertContext<'a> {
+    mint: &'a str,
+    signature: &'a str,
+    metrics: &'a TokenMetrics,
+    market_cap: f64,
+    market_cap_source: MarketCapSource,
+    trade: Option<&'a Trade>,
+    launch: Option<&'a Launch>,
+    matched: &'a RuleMatch,
+}
+
+/// Where the market cap used for filtering came from.
+#[derive(Clone, Copy)]
+enum MarketCapSource {
+    Moralis,
+    /// On-chain total supply times the price.
+    Supply,
+    /// Neither was available; only watched mints alert without a market cap.
+    Unknown,
+}
+
+impl MarketCapSource {
+    fn label(self) -> &'static str {
+        match self {
+            MarketCapSource::Moralis => "Moralis",
+            MarketCapSource::Supply => "on-chain supply × price",
+            MarketCapSource::Unknown => "unknown",
+        }
     }
 }
 
-#[derive(Debug, PartialEq)]
-pub enum FilterOutcome {
-    Pass,
-    Fail { reason: &'static str },
+/// Rule name used in logs for watched-wallet alerts.
+const WATCHLIST_RULE: &str = "watchlist";
+
+/// One alert line, e.g. `Received 1200.00 tokens of <mint>`.
+fn describe_activity(activity: &WalletActivity) -> String {
+    let mint = activity.mint.as_deref().unwrap_or("unknown mint");
+    let amount = activity
+        .tokens
+        .map(|tokens| format!("{tokens:.2} tokens"))
+        .unwrap_or_else(|| "tokens".to_string());
+    match activity.role {
+        WalletRole::Sender => format!("Sent {amount} of {mint}"),
+        WalletRole::Receiver => format!("Received {amount} of {mint}"),
+        WalletRole::Signer => format!("Signed a transaction moving {mint}"),
+    }
+}
+
+/// Launch lines of an alert, each starting with a newline.
+fn describe_launch(launch: &Launch) -> String {
+    let action = match launch.kind {
+        LaunchKind::PoolCreated => "pool created",
+        LaunchKind::TokenCreated => "token created",
+    };
+    let mut lines = format!("\nLaunch: {action} on {}", launch.venue);
+    if let Some(pool) = &launch.pool {
+        lines.push_str(&format!("\nPool: {pool}"));
+    }
+    if let Some(quote) = &launch.quote_mint {
+        lines.push_str(&format!("\nQuote: {quote}"));
+    }
+    match (launch.base_tokens, launch.quote_tokens) {
+        (Some(base), Some(quote)) => lines.push_str(&format!(
+            "\nInitial deposit: {base:.2} tokens + {quote:.4} quote tokens"
+        )),
+        (Some(base), None) => lines.push_str(&format!("\nInitial deposit: {base:.2} tokens")),
+        _ => {}
+    }
+    lines
+}
+
+/// The bonding-curve line of an alert, starting with a newline.
+fn describe_curve(curve: CurveUpdate) -> String {
+    match curve {
+        CurveUpdate::Progress {
+            fill_percent,
+            previous_percent,
+        } => {
+            let was = previous_percent
+                .map(|previous| format!(" (was {previous:.2}%)"))
+                .unwrap_or_default();
+            format!("\nBonding curve: {fill_percent:.2}% filled{was}")
+        }
+        CurveUpdate::Migrated {
+            destination,
+            last_percent,
+        } => {
+            let last = last_percent
+                .map(|last| format!(", curve last seen at {last:.2}%"))
+                .unwrap_or_default();
+            format!("\nMigrated from pump.fun to {destination}{last}")
+        }
+    }
+}
+
+/// Cooldowns are tracked per rule, so one rule firing does not silence the others.
+fn cooldown_key(rule: &RuleSettings, mint: &str) -> String {
+    format!("{}:{mint}", rule.name)
 }
 
 #[derive(Debug, Error)]
@@ -186,75 +1265,293 @@ pub enum AnalyzerError {
 
 #[cfg(test)]
 mod tests {
-    use super::{FilterOutcome, MarketCapBounds, MarketCapFilter};
+    use super::Analyzer;
+    use crate::{
+        config::AppConfig,
+        health::Readiness,
+        helius::HeliusWebhook,
+        latency::LatencyTrace,
+        metrics::Metrics,
+        performance::PerformanceTracker,
+        rpc::tests::{mock_rpc, result},
+        watchlist::{MintLists, Watchlist},
+    };
+    use axum::{
+        Json, Router,
+        extract::State,
+        http::StatusCode,
+        routing::{get, post},
+    };
+    use serde_json::{Value, json};
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
 
-    #[test]
-    fn passes_when_within_range() {
-        let filter = MarketCapFilter::new(MarketCapBounds {
-            min: Some(1_000.0),
-            max: Some(5_000_000.0),
-        });
+    const MINT: &str = "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263";
 
-        assert_eq!(FilterOutcome::Pass, filter.evaluate(10_000.0));
-        assert_eq!(FilterOutcome::Pass, filter.evaluate(1_000.0));
-        assert_eq!(FilterOutcome::Pass, filter.evaluate(5_000_000.0));
+    /// Canned Moralis and Telegram answers, and the alerts sent.
+    #[derive(Default)]
+    struct Upstream {
+        /// Moralis `/price` body for every mint.
+        price: Value,
+        /// `sendMessage` answers 500 while this is above zero.
+        failing_sends: usize,
+        sent: Vec<String>,
     }
 
-    #[test]
-    fn blocks_below_min() {
-        let filter = MarketCapFilter::new(MarketCapBounds {
-            min: Some(10_000.0),
-            max: Some(100_000.0),
-        });
+    type Shared = Arc<Mutex<Upstream>>;
 
-        assert_eq!(
-            FilterOutcome::Fail {
-                reason: "out_of_cap_range"
-            },
-            filter.evaluate(9_999.99)
-        );
+    /// Serves `upstream` as Moralis and Telegram (bot `token`) on a local port.
+    async fn serve(upstream: Shared) -> String {
+        let app = Router::new()
+            .route(
+                "/tokens/:mint/price",
+                get(|State(upstream): State<Shared>| async move {
+                    Json(upstream.lock().expect("upstream").price.clone())
+                }),
+            )
+            .route(
+                "/bottoken/sendMessage",
+                post(
+                    |State(upstream): State<Shared>, Json(body): Json<Value>| async move {
+                        let mut upstream = upstream.lock().expect("upstream");
+                        if upstream.failing_sends > 0 {
+                            upstream.failing_sends -= 1;
+                            return (
+                                StatusCode::INTERNAL_SERVER_ERROR,
+                                Json(json!({"ok": false})),
+                            );
+                        }
+                        let text = body["text"].as_str().unwrap_or_default().to_string();
+                        upstream.sent.push(text);
+                        let message_id = upstream.sent.len();
+                        (
+                            StatusCode::OK,
+                            Json(json!({"ok": true, "result": {"message_id": message_id}})),
+                        )
+                    },
+                ),
+            )
+            .with_state(upstream);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock upstream");
+        let addr = listener.local_addr().expect("mock upstream addr");
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        format!("http://{addr}")
     }
 
-    #[test]
-    fn blocks_above_max() {
-        let filter = MarketCapFilter::new(MarketCapBounds {
-            min: Some(10_000.0),
-            max: Some(100_000.0),
-        });
+    /// An analyzer using `url` for Moralis and Telegram and `rpc_url` for Solana RPC, with a
+    /// 1000 USD minimum cap and `extra` TOML appended.
+    fn analyzer(url: &str, rpc_url: &str, extra: &str) -> Analyzer {
+        let config = AppConfig::from_toml(&format!(
+            r#"
+[moralis]
+api_key = "key"
+base_url = "{url}"
 
-        assert_eq!(
-            FilterOutcome::Fail {
-                reason: "out_of_cap_range"
-            },
-            filter.evaluate(100_000.01)
+[telegram]
+bot_token = "token"
+chat_id = "42"
+api_base = "{url}"
+
+[rpc]
+url = "{rpc_url}"
+max_retries = 0
+
+[filter.market_cap]
+min_usd = 1000
+{extra}"#
+        ))
+        .expect("config");
+        Analyzer::new(
+            &config,
+            Watchlist::load(&[], None).expect("watchlist"),
+            MintLists::load(&config.mints).expect("mint lists"),
+            PerformanceTracker::default(),
+            Readiness::new(1),
+            Metrics::new(),
+        )
+    }
+
+    fn swap(signature: &str) -> HeliusWebhook {
+        serde_json::from_value(json!({
+            "signature": signature,
+            "type": "SWAP",
+            "events": {"tokenTransfers": [{"mint": MINT, "tokenAmount": 10.0}]}
+        }))
+        .expect("webhook")
+    }
+
+    async fn process(analyzer: &Analyzer, signature: &str) -> bool {
+        analyzer
+            .process_event(swap(signature), LatencyTrace::start(None))
+            .await
+            .is_ok()
+    }
+
+    #[tokio::test]
+    async fn processes_redelivery_after_failed_alert() {
+        let upstream = Shared::default();
+        {
+            let mut upstream = upstream.lock().expect("upstream");
+            upstream.price = json!({"usdPrice": 0.5, "marketCapUsd": 50_000.0});
+            upstream.failing_sends = 1;
+        }
+        let url = serve(upstream.clone()).await;
+        let analyzer = analyzer(&url, &url, "");
+
+        assert!(!process(&analyzer, "sig-1").await);
+        assert!(upstream.lock().expect("upstream").sent.is_empty());
+
+        assert!(process(&analyzer, "sig-1").await);
+        assert!(process(&analyzer, "sig-1").await);
+        let sent = &upstream.lock().expect("upstream").sent;
+        assert_eq!(1, sent.len());
+        assert!(sent[0].contains(MINT));
+    }
+
+    #[tokio::test]
+    async fn keeps_signature_after_partial_delivery() {
+        let upstream = Shared::default();
+        {
+            let mut upstream = upstream.lock().expect("upstream");
+            upstream.price = json!({"usdPrice": 0.5, "marketCapUsd": 50_000.0});
+            upstream.failing_sends = 1;
+        }
+        let url = serve(upstream.clone()).await;
+        let analyzer = analyzer(&url, &url, "[rules.second.market_cap]\nmin_usd = 1000");
+
+        assert!(!process(&analyzer, "sig-1").await);
+        assert_eq!(1, upstream.lock().expect("upstream").sent.len());
+
+        assert!(process(&analyzer, "sig-1").await);
+        assert_eq!(1, upstream.lock().expect("upstream").sent.len());
+    }
+
+    /// The rule named on each alert sent so far, in order.
+    fn alerted_rules(upstream: &Shared) -> Vec<String> {
+        let upstream = upstream.lock().expect("upstream");
+        upstream
+            .sent
+            .iter()
+            .map(|text| {
+                let (_, rule) = text.rsplit_once("Rule: ").expect("alert names its rule");
+                rule.lines().next().unwrap_or_default().to_string()
+            })
+            .collect()
+    }
+
+    async fn priced_upstream() -> (Shared, String) {
+        let upstream = Shared::default();
+        upstream.lock().expect("upstream").price =
+            json!({"usdPrice": 0.5, "marketCapUsd": 50_000.0});
+        let url = serve(upstream.clone()).await;
+        (upstream, url)
+    }
+
+    #[tokio::test]
+    async fn every_matching_rule_alerts() {
+        let (upstream, url) = priced_upstream().await;
+        let analyzer = analyzer(&url, &url, "[rules.second.market_cap]\nmin_usd = 1000");
+
+        assert!(process(&analyzer, "sig-1").await);
+        let mut rules = alerted_rules(&upstream);
+        rules.sort();
+        assert_eq!(vec!["default", "second"], rules);
+    }
+
+    #[tokio::test]
+    async fn cooldown_of_one_rule_does_not_silence_another() {
+        let (upstream, url) = priced_upstream().await;
+        let analyzer = analyzer(
+            &url,
+            &url,
+            "[filter]\ncooldown_secs = 600\n\n[rules.second.market_cap]\nmin_usd = 1000",
         );
+
+        assert!(process(&analyzer, "sig-1").await);
+        assert!(process(&analyzer, "sig-2").await);
+        let rules = alerted_rules(&upstream);
+        assert_eq!(3, rules.len());
+        assert_eq!("second", rules[2]);
     }
 
-    #[test]
-    fn open_lower_bound_allows_anything_below_max() {
-        let filter = MarketCapFilter::new(MarketCapBounds {
-            min: None,
-            max: Some(1_000_000.0),
-        });
+    #[tokio::test]
+    async fn failing_rule_does_not_stop_the_others() {
+        let (upstream, url) = priced_upstream().await;
+        let analyzer = analyzer(&url, &url, "[rules.large.market_cap]\nmin_usd = 1000000");
 
-        assert_eq!(FilterOutcome::Pass, filter.evaluate(0.0));
-        assert_eq!(FilterOutcome::Pass, filter.evaluate(999_999.99));
+        assert!(process(&analyzer, "sig-1").await);
+        assert_eq!(vec!["default"], alerted_rules(&upstream));
     }
 
-    #[test]
-    fn open_upper_bound_allows_anything_above_min() {
-        let filter = MarketCapFilter::new(MarketCapBounds {
-            min: Some(5_000.0),
-            max: None,
-        });
+    #[tokio::test]
+    async fn falls_back_to_supply_times_price_without_moralis_cap() {
+        let upstream = Shared::default();
+        {
+            let mut upstream = upstream.lock().expect("upstream");
+            upstream.price = json!({"usdPrice": 0.002});
+        }
+        let rpc_url = mock_rpc(Duration::ZERO, |_, request| {
+            assert_eq!("getTokenSupply", request["method"]);
+            result(json!({
+                "context": {"slot": 1},
+                "value": {"amount": "1000000000000", "decimals": 6, "uiAmount": 1000000.0}
+            }))
+        })
+        .await;
+        let analyzer = analyzer(&serve(upstream.clone()).await, &rpc_url, "");
 
-        assert_eq!(
-            FilterOutcome::Fail {
-                reason: "out_of_cap_range"
-            },
-            filter.evaluate(4_999.99)
+        assert!(process(&analyzer, "sig-supply").await);
+        let sent = &upstream.lock().expect("upstream").sent;
+        assert_eq!(1, sent.len());
+        assert!(
+            sent[0].contains("Market cap (USD): 2000.00 (on-chain supply × price)"),
+            "{}",
+            sent[0]
         );
-        assert_eq!(FilterOutcome::Pass, filter.evaluate(5_000.0));
-        assert_eq!(FilterOutcome::Pass, filter.evaluate(50_000_000.0));
+    }
+
+    #[tokio::test]
+    async fn skips_without_cap_when_supply_lookup_fails() {
+        let upstream = Shared::default();
+        upstream.lock().expect("upstream").price = json!({"usdPrice": 0.002});
+        let rpc_url = mock_rpc(Duration::ZERO, |_, _| (StatusCode::BAD_GATEWAY, json!({}))).await;
+        let analyzer = analyzer(&serve(upstream.clone()).await, &rpc_url, "");
+
+        assert!(process(&analyzer, "sig-no-supply").await);
+        assert!(upstream.lock().expect("upstream").sent.is_empty());
+        let metrics = analyzer.metrics.render().expect("render");
+        assert!(
+            metrics
+                .contains(r#"solana_monitor_events_skipped_total{reason="missing_market_cap"} 1"#)
+        );
+    }
+
+    #[tokio::test]
+    async fn watched_mint_alerts_without_market_cap_or_matching_event_type() {
+        let upstream = Shared::default();
+        upstream.lock().expect("upstream").price = json!({});
+        let url = serve(upstream.clone()).await;
+        let unwatched = analyzer(&url, &url, "[filter]\nevent_types = [\"TRANSFER\"]\n");
+        let watched = analyzer(
+            &url,
+            &url,
+            &format!(
+                "[filter]\nevent_types = [\"TRANSFER\"]\ncooldown_secs = 60\n\n[mints]\nwatched = [\"{MINT}\"]\n"
+            ),
+        );
+
+        assert!(process(&unwatched, "sig-unwatched").await);
+        assert!(upstream.lock().expect("upstream").sent.is_empty());
+
+        assert!(process(&watched, "sig-watched").await);
+        // The cooldown still holds back a second alert for the same mint.
+        assert!(process(&watched, "sig-watched-again").await);
+        let sent = &upstream.lock().expect("upstream").sent;
+        assert_eq!(1, sent.len());
+        assert!(sent[0].contains("Market cap (USD): n/a"), "{}", sent[0]);
     }
 }
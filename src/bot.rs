@@ -0,0 +1,352 @@
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::{
+    metrics::Metrics,
+    performance::PerformanceTracker,
+    telegram::TelegramClient,
+    watchlist::{MintLists, UnwatchOutcome, WalletSource, WatchOutcome, Watchlist},
+};
+
+/// Seconds each `getUpdates` call waits for new messages.
+const POLL_TIMEOUT_SECS: u64 = 30;
+/// Pause after a failed poll before asking again.
+const RETRY_DELAY: Duration = Duration::from_secs(5);
+
+const HELP: &str = "Commands:\n\
+/watch_wallet <address> — alert on every token transfer of a wallet\n\
+/unwatch_wallet <address> — stop watching a wallet\n\
+/watchlist — list watched wallets\n\
+/watch_mint <mint> — alert on a mint whatever the rule filters say\n\
+/unwatch_mint <mint> — stop forcing alerts for a mint\n\
+/ignore_mint <mint> — never look at a mint again\n\
+/unignore_mint <mint> — stop ignoring a mint\n\
+/mints — list watched and ignored mints\n\
+/stats — hit rate and average return of alerts per rule";
+
+/// A bot command sent to the configured chat.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    WatchWallet(String),
+    UnwatchWallet(String),
+    Watchlist,
+    WatchMint(String),
+    UnwatchMint(String),
+    IgnoreMint(String),
+    UnignoreMint(String),
+    Mints,
+    Stats,
+    Help,
+}
+
+impl Command {
+    /// `None` for plain text; `Some(Err(reply))` for unknown or malformed commands.
+    pub fn parse(text: &str) -> Option<Result<Self, String>> {
+        let mut words = text.split_whitespace();
+        let command = words.next()?.strip_prefix('/')?;
+        // In groups Telegram appends the bot name: `/watchlist@my_bot`.
+        let command = command.split_once('@').map_or(command, |(name, _)| name);
+        let argument = words.next().map(str::to_string);
+
+        let with_address = |build: fn(String) -> Command| {
+            argument
+                .clone()
+                .map(build)
+                .ok_or_else(|| format!("Usage: /{command} <address>"))
+        };
+        let parsed = match command {
+            "watch_wallet" => with_address(Command::WatchWallet),
+            "unwatch_wallet" => with_address(Command::UnwatchWallet),
+            "watchlist" => Ok(Command::Watchlist),
+            "watch_mint" => with_address(Command::WatchMint),
+            "unwatch_mint" => with_address(Command::UnwatchMint),
+            "ignore_mint" => with_address(Command::IgnoreMint),
+            "unignore_mint" => with_address(Command::UnignoreMint),
+            "mints" => Ok(Command::Mints),
+            "stats" => Ok(Command::Stats),
+            "help" | "start" => Ok(Command::Help),
+            _ => Err(format!("Unknown command /{command}\n\n{HELP}")),
+        };
+        Some(parsed)
+    }
+}
+
+/// Which managed list a command changes.
+#[derive(Clone, Copy)]
+enum List {
+    Wallets,
+    WatchedMints,
+    IgnoredMints,
+}
+
+impl List {
+    /// Value of the `list` field in `watchlist_changed` logs.
+    fn key(self) -> &'static str {
+        match self {
+            List::Wallets => "wallets",
+            List::WatchedMints => "watched_mints",
+            List::IgnoredMints => "ignored_mints",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            List::Wallets => "wallet watchlist",
+            List::WatchedMints => "mint watchlist",
+            List::IgnoredMints => "mint ignore list",
+        }
+    }
+}
+
+/// Answers bot commands from the configured chat; messages from other chats are ignored.
+pub struct Bot {
+    telegram: TelegramClient,
+    chat_id: String,
+    watchlist: Watchlist,
+    mints: MintLists,
+    performance: PerformanceTracker,
+    metrics: Metrics,
+}
+
+impl Bot {
+    pub fn new(
+        telegram: TelegramClient,
+        chat_id: String,
+        watchlist: Watchlist,
+        mints: MintLists,
+        performance: PerformanceTracker,
+        metrics: Metrics,
+    ) -> Self {
+        Self {
+            telegram,
+            chat_id,
+            watchlist,
+            mints,
+            performance,
+            metrics,
+        }
+    }
+
+    /// Polls for commands until the process exits.
+    pub async fn run(self) {
+        info!("bot_commands_enabled");
+        let mut offset = 0;
+        loop {
+            let updates = match self.telegram.get_updates(offset, POLL_TIMEOUT_SECS).await {
+                Ok(updates) => updates,
+                Err(err) => {
+                    self.metrics.provider_error("telegram", &err.status_label());
+                    warn!(error = ?err, source = "telegram", "bot_poll_failed");
+                    tokio::time::sleep(RETRY_DELAY).await;
+                    continue;
+                }
+            };
+
+            for update in updates {
+                offset = offset.max(update.update_id + 1);
+                let Some(message) = update.message else {
+                    continue;
+                };
+                let Some(command) = message.text.as_deref().and_then(Command::parse) else {
+                    continue;
+                };
+                let chat_id = message.chat.id.to_string();
+                if chat_id != self.chat_id {
+                    warn!(chat_id = chat_id.as_str(), "bot_command_rejected");
+                    continue;
+                }
+
+                let reply = match command {
+                    Ok(command) => self.handle(command),
+                    Err(reply) => reply,
+                };
+                if let Err(err) = self.telegram.send_message(&self.chat_id, &reply).await {
+                    self.metrics.provider_error("telegram", &err.status_label());
+                    warn!(error = ?err, source = "telegram", "bot_reply_failed");
+                }
+            }
+        }
+    }
+
+    fn handle(&self, command: Command) -> String {
+        match command {
+            Command::WatchWallet(wallet) => self.add(List::Wallets, &wallet),
+            Command::UnwatchWallet(wallet) => self.remove(List::Wallets, &wallet),
+            Command::Watchlist => describe(&[("Watched wallets", &self.watchlist)]),
+            Command::WatchMint(mint) => {
+                if self.mints.ignored.contains(&mint) {
+                    return format!("{mint} is ignored; /unignore_mint it first");
+                }
+                self.add(List::WatchedMints, &mint)
+            }
+            Command::UnwatchMint(mint) => self.remove(List::WatchedMints, &mint),
+            Command::IgnoreMint(mint) => self.add(List::IgnoredMints, &mint),
+            Command::UnignoreMint(mint) => self.remove(List::IgnoredMints, &mint),
+            Command::Mints => describe(&[
+                ("Watched mints", &self.mints.watched),
+                ("Ignored mints", &self.mints.ignored),
+            ]),
+            Command::Stats => self.performance.describe(),
+            Command::Help => HELP.to_string(),
+        }
+    }
+
+    fn list(&self, list: List) -> &Watchlist {
+        match list {
+            List::Wallets => &self.watchlist,
+            List::WatchedMints => &self.mints.watched,
+            List::IgnoredMints => &self.mints.ignored,
+        }
+    }
+
+    fn add(&self, list: List, address: &str) -> String {
+        match self.list(list).watch(address) {
+            Ok(WatchOutcome::Added) => {
+                log_change(list, "add", address);
+                format!("Added {address} to the {}", list.label())
+            }
+            Ok(WatchOutcome::AlreadyWatched) => {
+                format!("{address} is already on the {}", list.label())
+            }
+            Err(err) => {
+                warn!(list = list.key(), address = address, error = %err, "watchlist_update_failed");
+                format!("Could not add {address}: {err}")
+            }
+        }
+    }
+
+    fn remove(&self, list: List, address: &str) -> String {
+        match self.list(list).unwatch(address) {
+            Ok(UnwatchOutcome::Removed) => {
+                log_change(list, "remove", address);
+                format!("Removed {address} from the {}", list.label())
+            }
+            Ok(UnwatchOutcome::NotWatched) => format!("{address} is not on the {}", list.label()),
+            Ok(UnwatchOutcome::Configured) => {
+                format!("{address} is set in the config file; remove it there")
+            }
+            Err(err) => {
+                warn!(list = list.key(), address = address, error = %err, "watchlist_update_failed");
+                format!("Could not remove {address}: {err}")
+            }
+        }
+    }
+}
+
+fn log_change(list: List, action: &'static str, address: &str) {
+    info!(
+        list = list.key(),
+        action = action,
+        address = address,
+        source = "telegram",
+        "watchlist_changed"
+    );
+}
+
+/// One titled block per list; config entries are marked.
+fn describe(lists: &[(&str, &Watchlist)]) -> String {
+    let blocks: Vec<String> = lists
+        .iter()
+        .map(|(title, list)| {
+            let entries = list.list();
+            if entries.is_empty() {
+                return format!("{title}: none");
+            }
+            let mut block = format!("{title} ({}):", entries.len());
+            for (address, source) in entries {
+                let note = match source {
+                    WalletSource::Config => " (config)",
+                    WalletSource::Command => "",
+                };
+                block.push_str(&format!("\n{address}{note}"));
+            }
+            block
+        })
+        .collect();
+    blocks.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bot, Command};
+    use crate::{
+        config::MintListSettings,
+        metrics::Metrics,
+        performance::PerformanceTracker,
+        telegram::TelegramClient,
+        watchlist::{MintLists, MintStatus, Watchlist},
+    };
+    use std::time::Duration;
+
+    const WALLET: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+    const MINT: &str = "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263";
+
+    #[test]
+    fn parses_commands_and_ignores_plain_text() {
+        assert_eq!(None, Command::parse("hello"));
+        assert_eq!(
+            Some(Ok(Command::WatchWallet(WALLET.to_string()))),
+            Command::parse(&format!("/watch_wallet@monitor_bot  {WALLET}"))
+        );
+        assert_eq!(Some(Ok(Command::Watchlist)), Command::parse("/watchlist"));
+        assert_eq!(Some(Ok(Command::Stats)), Command::parse("/stats"));
+        assert!(matches!(
+            Command::parse("/unwatch_wallet"),
+            Some(Err(reply)) if reply.starts_with("Usage")
+        ));
+        assert!(matches!(Command::parse("/moon"), Some(Err(_))));
+    }
+
+    #[test]
+    fn list_commands_update_wallets_and_mints() {
+        let watchlist = Watchlist::load(&[], None).expect("watchlist");
+        let mints = MintLists::load(&MintListSettings::default()).expect("mint lists");
+        let bot = Bot::new(
+            TelegramClient::new("token".to_string(), "http://127.0.0.1:9".to_string()),
+            "42".to_string(),
+            watchlist.clone(),
+            mints.clone(),
+            PerformanceTracker::default(),
+            Metrics::new(),
+        );
+
+        let reply = bot.handle(Command::WatchWallet(WALLET.to_string()));
+        assert_eq!(format!("Added {WALLET} to the wallet watchlist"), reply);
+        assert!(watchlist.contains(WALLET));
+        assert!(bot.handle(Command::Watchlist).contains(WALLET));
+        bot.handle(Command::UnwatchWallet(WALLET.to_string()));
+        assert!(!watchlist.contains(WALLET));
+        assert_eq!("Watched wallets: none", bot.handle(Command::Watchlist));
+
+        bot.handle(Command::IgnoreMint(MINT.to_string()));
+        assert_eq!(MintStatus::Ignored, mints.status(MINT));
+        assert!(
+            bot.handle(Command::WatchMint(MINT.to_string()))
+                .contains("/unignore_mint")
+        );
+        bot.handle(Command::UnignoreMint(MINT.to_string()));
+        bot.handle(Command::WatchMint(MINT.to_string()));
+        assert_eq!(MintStatus::Watched, mints.status(MINT));
+        assert!(bot.handle(Command::Mints).starts_with("Watched mints (1):"));
+    }
+
+    #[test]
+    fn stats_command_replies_with_performance_report() {
+        let performance = PerformanceTracker::default();
+        let bot = Bot::new(
+            TelegramClient::new("token".to_string(), "http://127.0.0.1:9".to_string()),
+            "42".to_string(),
+            Watchlist::load(&[], None).expect("watchlist"),
+            MintLists::load(&MintListSettings::default()).expect("mint lists"),
+            performance.clone(),
+            Metrics::new(),
+        );
+        assert_eq!("No alerts followed up yet", bot.handle(Command::Stats));
+
+        performance.record("micro", Duration::from_secs(60), -12.5, false);
+        assert_eq!(
+            "Rule micro (0 alerts):\n+1m: 0% hits (0/1), avg -12.5%",
+            bot.handle(Command::Stats)
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Environment variable consulted when `--config` is not given.
+const CONFIG_PATH_ENV: &str = "CONFIG_PATH";
+
+pub const USAGE: &str = "\
+usage: solana_monitor [--config <path>] [--print-config] [--healthcheck]
+
+  --config <path>   TOML config file (defaults to $CONFIG_PATH if set)
+  --print-config    print the effective config with secrets redacted and exit
+  --healthcheck     request /healthz on the configured listener and exit 0 if it answers
+  -h, --help        show this message";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct CliArgs {
+    pub config_path: Option<PathBuf>,
+    pub print_config: bool,
+    pub healthcheck: bool,
+    pub help: bool,
+}
+
+impl CliArgs {
+    pub fn from_env() -> Result<Self, CliError> {
+        let mut args = Self::parse(std::env::args().skip(1))?;
+        if args.config_path.is_none() {
+            args.config_path = std::env::var_os(CONFIG_PATH_ENV).map(PathBuf::from);
+        }
+        Ok(args)
+    }
+
+    fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, CliError> {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--config" => {
+                    let path = args.next().ok_or(CliError::MissingValue("--config"))?;
+                    parsed.config_path = Some(PathBuf::from(path));
+                }
+                "--print-config" => parsed.print_config = true,
+                "--healthcheck" => parsed.healthcheck = true,
+                "-h" | "--help" => parsed.help = true,
+                other => match other.strip_prefix("--config=") {
+                    Some(path) => parsed.config_path = Some(PathBuf::from(path)),
+                    None => return Err(CliError::UnknownArgument(other.to_string())),
+                },
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error("missing value for {0}")]
+    MissingValue(&'static str),
+    #[error("unknown argument {0}")]
+    UnknownArgument(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CliArgs, CliError};
+    use std::path::PathBuf;
+
+    fn parse(args: &[&str]) -> Result<CliArgs, CliError> {
+        CliArgs::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn parses_config_path_in_both_forms() {
+        let spaced = parse(&["--config", "monitor.toml", "--print-config"]).expect("parse");
+        assert_eq!(Some(PathBuf::from("monitor.toml")), spaced.config_path);
+        assert!(spaced.print_config);
+
+        let inline = parse(&["--config=monitor.toml", "--healthcheck"]).expect("parse");
+        assert_eq!(Some(PathBuf::from("monitor.toml")), inline.config_path);
+        assert!(inline.healthcheck);
+    }
+
+    #[test]
+    fn rejects_unknown_and_incomplete_arguments() {
+        assert!(matches!(
+            parse(&["--verbose"]),
+            Err(CliError::UnknownArgument(_))
+        ));
+        assert!(matches!(
+            parse(&["--config"]),
+            Err(CliError::MissingValue("--config"))
+        ));
+    }
+}
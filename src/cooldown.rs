@@ -0,0 +1,77 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Suppresses repeat alerts for the same key until its cooldown expires.
+///
+/// The cooldown length is fixed when the alert is recorded, so a later profile
+/// switch does not shorten or extend a cooldown already running.
+#[derive(Clone, Default)]
+pub struct AlertCooldowns {
+    expires_at: Arc<Mutex<HashMap<String, Instant>>>,
+}
+
+impl AlertCooldowns {
+    /// Time left before `key` may alert again, if it is cooling down.
+    pub fn remaining(&self, key: &str) -> Option<Duration> {
+        self.remaining_at(key, Instant::now())
+    }
+
+    pub fn record(&self, key: &str, cooldown: Duration) {
+        self.record_at(key, cooldown, Instant::now());
+    }
+
+    fn remaining_at(&self, key: &str, now: Instant) -> Option<Duration> {
+        let expires_at = self.expires_at.lock().expect("alert cooldowns poisoned");
+        expires_at
+            .get(key)
+            .filter(|expiry| **expiry > now)
+            .map(|expiry| expiry.duration_since(now))
+    }
+
+    fn record_at(&self, key: &str, cooldown: Duration, now: Instant) {
+        if cooldown.is_zero() {
+            return;
+        }
+
+        let mut expires_at = self.expires_at.lock().expect("alert cooldowns poisoned");
+        expires_at.retain(|_, expiry| *expiry > now);
+        expires_at.insert(key.to_string(), now + cooldown);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AlertCooldowns;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn blocks_until_cooldown_expires() {
+        let cooldowns = AlertCooldowns::default();
+        let start = Instant::now();
+
+        cooldowns.record_at("mint", Duration::from_secs(60), start);
+
+        assert_eq!(
+            Some(Duration::from_secs(30)),
+            cooldowns.remaining_at("mint", start + Duration::from_secs(30))
+        );
+        assert_eq!(
+            None,
+            cooldowns.remaining_at("mint", start + Duration::from_secs(60))
+        );
+        assert_eq!(None, cooldowns.remaining_at("other", start));
+    }
+
+    #[test]
+    fn zero_cooldown_is_not_recorded() {
+        let cooldowns = AlertCooldowns::default();
+        let start = Instant::now();
+
+        cooldowns.record_at("mint", Duration::ZERO, start);
+
+        assert_eq!(None, cooldowns.remaining_at("mint", start));
+    }
+}
@@ -0,0 +1,132 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Bounded, time-expiring set of transaction signatures already handed to the analyzer.
+///
+/// Helius retries webhook deliveries, so the same signature can arrive more than once.
+#[derive(Clone)]
+pub struct SignatureCache {
+    inner: Arc<Mutex<SeenSignatures>>,
+}
+
+struct SeenSignatures {
+    capacity: usize,
+    ttl: Duration,
+    order: VecDeque<(Instant, String)>,
+    seen: HashSet<String>,
+}
+
+impl SignatureCache {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(SeenSignatures {
+                capacity,
+                ttl,
+                order: VecDeque::new(),
+                seen: HashSet::new(),
+            })),
+        }
+    }
+
+    /// Records `signature` and returns `true` if it was not seen within the TTL window.
+    pub fn insert(&self, signature: &str) -> bool {
+        self.insert_at(signature, Instant::now())
+    }
+
+    /// Forgets `signature` so a redelivery of it is processed again.
+    pub fn remove(&self, signature: &str) {
+        let mut inner = self.inner.lock().expect("signature cache poisoned");
+        if inner.seen.remove(signature) {
+            inner.order.retain(|(_, seen)| seen != signature);
+        }
+    }
+
+    fn insert_at(&self, signature: &str, now: Instant) -> bool {
+        let mut inner = self.inner.lock().expect("signature cache poisoned");
+        inner.evict_expired(now);
+
+        if inner.seen.contains(signature) {
+            return false;
+        }
+
+        if inner.capacity == 0 {
+            return true;
+        }
+
+        while inner.order.len() >= inner.capacity {
+            inner.pop_oldest();
+        }
+
+        inner.seen.insert(signature.to_string());
+        inner.order.push_back((now, signature.to_string()));
+        true
+    }
+}
+
+impl SeenSignatures {
+    fn evict_expired(&mut self, now: Instant) {
+        while let Some((inserted_at, _)) = self.order.front() {
+            if now.duration_since(*inserted_at) < self.ttl {
+                break;
+            }
+            self.pop_oldest();
+        }
+    }
+
+    fn pop_oldest(&mut self) {
+        if let Some((_, signature)) = self.order.pop_front() {
+            self.seen.remove(&signature);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SignatureCache;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn rejects_repeated_signature_within_ttl() {
+        let cache = SignatureCache::new(16, Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert!(cache.insert_at("sig-a", now));
+        assert!(!cache.insert_at("sig-a", now + Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn accepts_signature_again_after_ttl() {
+        let cache = SignatureCache::new(16, Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert!(cache.insert_at("sig-a", now));
+        assert!(cache.insert_at("sig-a", now + Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn accepts_signature_again_after_removal() {
+        let cache = SignatureCache::new(16, Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert!(cache.insert_at("sig-a", now));
+        cache.remove("sig-a");
+        assert!(cache.insert_at("sig-a", now));
+        assert!(!cache.insert_at("sig-a", now));
+    }
+
+    #[test]
+    fn evicts_oldest_when_full() {
+        let cache = SignatureCache::new(2, Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert!(cache.insert_at("sig-a", now));
+        assert!(cache.insert_at("sig-b", now));
+        assert!(cache.insert_at("sig-c", now));
+
+        assert!(cache.insert_at("sig-a", now));
+        assert!(!cache.insert_at("sig-c", now));
+    }
+}
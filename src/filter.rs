@@ -0,0 +1,1116 @@
+use std::fmt;
+
+use crate::{
+    config::{
+        Bounds, CurveSettings, GrowthSettings, RiskAction, RiskSettings, RuleSettings,
+        VolumeSpikeSettings, WhaleSettings,
+    },
+    growth::PriceHistory,
+    helius::Trade,
+    moralis::TokenMetrics,
+    pumpfun::CurveUpdate,
+    risk::RiskReport,
+    volume::TradeVolumes,
+};
+
+/// What the filters know about an event once market data has been fetched.
+pub struct EventContext<'a> {
+    pub mint: &'a str,
+    pub market_cap: f64,
+    pub metrics: &'a TokenMetrics,
+    pub prices: &'a PriceHistory,
+    pub volumes: &'a TradeVolumes,
+    /// The fee payer's buy or sell of the mint, for swap events.
+    pub trade: Option<&'a Trade>,
+    /// pump.fun bonding-curve progress or migration in this event.
+    pub curve: Option<&'a CurveUpdate>,
+    /// On-chain risk flags, when a rule asked for them and the lookup succeeded.
+    pub risk: Option<&'a RiskReport>,
+}
+
+/// One stage of a [`FilterPipeline`].
+pub trait Filter: Send + Sync {
+    /// Stable name used in logs and metric labels.
+    fn name(&self) -> &'static str;
+
+    fn evaluate(&self, event: &EventContext<'_>) -> FilterOutcome;
+}
+
+/// A filter verdict: a stable `code` for metrics plus what was measured.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterOutcome {
+    pub passed: bool,
+    pub code: &'static str,
+    pub detail: Detail,
+    /// Concerns that did not fail the filter but belong in the alert.
+    pub warnings: Vec<String>,
+}
+
+/// Why a filter decided the way it did.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Detail {
+    /// The measured value compared against the bound that decided the verdict.
+    Measured(Measurement),
+    /// Free text when there is no single value to compare.
+    Note(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement {
+    pub metric: &'static str,
+    pub value: f64,
+    pub comparison: Comparison,
+    pub bound: f64,
+    pub unit: &'static str,
+}
+
+/// How `value` relates to `bound` in a [`Measurement`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Comparison::Less => "<",
+            Comparison::LessOrEqual => "<=",
+            Comparison::Greater => ">",
+            Comparison::GreaterOrEqual => ">=",
+        }
+    }
+}
+
+impl FilterOutcome {
+    pub fn pass(code: &'static str, detail: Detail) -> Self {
+        Self {
+            passed: true,
+            code,
+            detail,
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn fail(code: &'static str, detail: Detail) -> Self {
+        Self {
+            passed: false,
+            code,
+            detail,
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn with_warnings(mut self, warnings: Vec<String>) -> Self {
+        self.warnings = warnings;
+        self
+    }
+
+    pub fn decision(&self) -> &'static str {
+        if self.passed { "pass" } else { "fail" }
+    }
+
+    pub fn measurement(&self) -> Option<&Measurement> {
+        match &self.detail {
+            Detail::Measured(measurement) => Some(measurement),
+            Detail::Note(_) => None,
+        }
+    }
+
+    /// Human-readable reason, e.g. `market_cap 42000 < 100000`.
+    pub fn reason(&self) -> String {
+        match &self.detail {
+            Detail::Measured(measurement) => measurement.to_string(),
+            Detail::Note(note) => note.clone(),
+        }
+    }
+}
+
+impl fmt::Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}{unit} {} {}{unit}",
+            self.metric,
+            format_number(self.value),
+            self.comparison.symbol(),
+            format_number(self.bound),
+            unit = self.unit,
+        )
+    }
+}
+
+/// Whole numbers print without decimals, everything else with two.
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.2}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Verdict {
+    pub filter: &'static str,
+    pub outcome: FilterOutcome,
+}
+
+/// Filters applied in order; evaluation stops at the first failure.
+pub struct FilterPipeline {
+    stages: Vec<Box<dyn Filter>>,
+}
+
+impl FilterPipeline {
+    pub fn new(stages: Vec<Box<dyn Filter>>) -> Self {
+        Self { stages }
+    }
+
+    /// The stages configured for `rule`; unset criteria add no stage.
+    pub fn for_rule(rule: &RuleSettings) -> Self {
+        let mut stages: Vec<Box<dyn Filter>> =
+            vec![Box::new(RangeFilter::market_cap(rule.market_cap.clone()))];
+        let ranges = [
+            (
+                &rule.liquidity,
+                RangeFilter::liquidity as fn(Bounds) -> RangeFilter,
+            ),
+            (&rule.volume_24h, RangeFilter::volume_24h),
+            (&rule.holders, RangeFilter::holders),
+            (&rule.age, RangeFilter::age),
+        ];
+        for (bounds, filter) in ranges {
+            if bounds.is_set() {
+                stages.push(Box::new(filter(bounds.clone())));
+            }
+        }
+        if let Some(growth) = &rule.growth {
+            stages.push(Box::new(GrowthFilter::new(growth.clone())));
+        }
+        if let Some(spike) = &rule.volume_spike {
+            stages.push(Box::new(VolumeSpikeFilter::new(spike.clone())));
+        }
+        if rule.whale.is_active() {
+            stages.push(Box::new(WhaleFilter::new(rule.whale.clone())));
+        }
+        if rule.curve.is_active() {
+            stages.push(Box::new(CurveFilter::new(rule.curve.clone())));
+        }
+        if rule.risk.is_active() {
+            stages.push(Box::new(RiskFilter::new(rule.risk.clone())));
+        }
+        Self::new(stages)
+    }
+
+    /// Stands in for a rule's own filters when the mint is on the mint watchlist.
+    pub fn watched_mint() -> Self {
+        Self::new(vec![Box::new(WatchedMintFilter)])
+    }
+
+    /// Runs every stage up to and including the first failure.
+    pub fn run(&self, event: &EventContext<'_>) -> Vec<Verdict> {
+        let mut verdicts = Vec::with_capacity(self.stages.len());
+        for stage in &self.stages {
+            let outcome = stage.evaluate(event);
+            let passed = outcome.passed;
+            verdicts.push(Verdict {
+                filter: stage.name(),
+                outcome,
+            });
+            if !passed {
+                break;
+            }
+        }
+        verdicts
+    }
+}
+
+/// `true` when no verdict failed.
+pub fn all_passed(verdicts: &[Verdict]) -> bool {
+    verdicts.iter().all(|verdict| verdict.outcome.passed)
+}
+
+/// Always passes: watched mints alert whatever the rule's bounds say.
+pub struct WatchedMintFilter;
+
+impl Filter for WatchedMintFilter {
+    fn name(&self) -> &'static str {
+        "mint_watchlist"
+    }
+
+    fn evaluate(&self, _event: &EventContext<'_>) -> FilterOutcome {
+        FilterOutcome::pass(
+            "watched_mint",
+            Detail::Note("mint is on the watchlist".to_string()),
+        )
+    }
+}
+
+/// Checks one measured value against optional inclusive bounds.
+#[derive(Clone)]
+pub struct RangeFilter {
+    metric: &'static str,
+    bounds: Bounds,
+    value: fn(&EventContext<'_>) -> Option<f64>,
+    unit: &'static str,
+    out_of_range: &'static str,
+    missing: &'static str,
+}
+
+impl RangeFilter {
+    pub fn market_cap(bounds: Bounds) -> Self {
+        Self {
+            metric: "market_cap",
+            bounds,
+            value: |event| Some(event.market_cap),
+            unit: "",
+            out_of_range: "out_of_cap_range",
+            missing: "missing_market_cap",
+        }
+    }
+
+    pub fn liquidity(bounds: Bounds) -> Self {
+        Self {
+            metric: "liquidity",
+            bounds,
+            value: |event| event.metrics.liquidity_usd,
+            unit: "",
+            out_of_range: "out_of_liquidity_range",
+            missing: "missing_liquidity",
+        }
+    }
+
+    pub fn volume_24h(bounds: Bounds) -> Self {
+        Self {
+            metric: "volume_24h",
+            bounds,
+            value: |event| event.metrics.volume_24h_usd,
+            unit: "",
+            out_of_range: "out_of_volume_range",
+            missing: "missing_volume",
+        }
+    }
+
+    pub fn holders(bounds: Bounds) -> Self {
+        Self {
+            metric: "holders",
+            bounds,
+            value: |event| event.metrics.holders.map(|holders| holders as f64),
+            unit: "",
+            out_of_range: "out_of_holder_range",
+            missing: "missing_holders",
+        }
+    }
+
+    /// Seconds since the token was created.
+    pub fn age(bounds: Bounds) -> Self {
+        Self {
+            metric: "age",
+            bounds,
+            value: |event| event.metrics.age_secs(),
+            unit: "s",
+            out_of_range: "out_of_age_range",
+            missing: "missing_creation_time",
+        }
+    }
+}
+
+impl Filter for RangeFilter {
+    fn name(&self) -> &'static str {
+        self.metric
+    }
+
+    fn evaluate(&self, event: &EventContext<'_>) -> FilterOutcome {
+        let Some(value) = (self.value)(event) else {
+            return FilterOutcome::fail(
+                self.missing,
+                Detail::Note(format!("{} unavailable", self.metric)),
+            );
+        };
+
+        let measured = |comparison, bound| {
+            Detail::Measured(Measurement {
+                metric: self.metric,
+                value,
+                comparison,
+                bound,
+                unit: self.unit,
+            })
+        };
+
+        if let Some(min) = self.bounds.min
+            && value < min
+        {
+            return FilterOutcome::fail(self.out_of_range, measured(Comparison::Less, min));
+        }
+
+        if let Some(max) = self.bounds.max
+            && value > max
+        {
+            return FilterOutcome::fail(self.out_of_range, measured(Comparison::Greater, max));
+        }
+
+        let detail = match (self.bounds.min, self.bounds.max) {
+            (Some(min), _) => measured(Comparison::GreaterOrEqual, min),
+            (None, Some(max)) => measured(Comparison::LessOrEqual, max),
+            (None, None) => Detail::Note(format!("no {} bounds", self.metric)),
+        };
+        FilterOutcome::pass("within_range", detail)
+    }
+}
+
+/// Price growth from the lowest sample in the window to the latest one.
+pub struct GrowthFilter {
+    settings: GrowthSettings,
+}
+
+impl GrowthFilter {
+    pub fn new(settings: GrowthSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl Filter for GrowthFilter {
+    fn name(&self) -> &'static str {
+        "growth"
+    }
+
+    fn evaluate(&self, event: &EventContext<'_>) -> FilterOutcome {
+        let window = self.settings.window;
+        let Some(growth) = event.prices.growth_percent(event.mint, window) else {
+            return FilterOutcome::fail(
+                "insufficient_history",
+                Detail::Note(format!(
+                    "fewer than 2 price samples in {}s",
+                    window.as_secs()
+                )),
+            );
+        };
+
+        let measured = |comparison| {
+            Detail::Measured(Measurement {
+                metric: "growth",
+                value: growth,
+                comparison,
+                bound: self.settings.percent,
+                unit: "%",
+            })
+        };
+        if growth < self.settings.percent {
+            FilterOutcome::fail("below_threshold", measured(Comparison::Less))
+        } else {
+            FilterOutcome::pass("above_threshold", measured(Comparison::GreaterOrEqual))
+        }
+    }
+}
+
+/// Swap volume in the latest window against the trailing baseline.
+pub struct VolumeSpikeFilter {
+    settings: VolumeSpikeSettings,
+}
+
+impl VolumeSpikeFilter {
+    pub fn new(settings: VolumeSpikeSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl Filter for VolumeSpikeFilter {
+    fn name(&self) -> &'static str {
+        "volume_spike"
+    }
+
+    fn evaluate(&self, event: &EventContext<'_>) -> FilterOutcome {
+        let VolumeSpikeSettings {
+            window,
+            baseline,
+            min_ratio,
+            min_trades,
+        } = self.settings;
+        let spike = event.volumes.spike(event.mint, window, baseline);
+
+        if (spike.recent.trades as u64) < min_trades {
+            return FilterOutcome::fail(
+                "too_few_trades",
+                Detail::Measured(Measurement {
+                    metric: "trades",
+                    value: spike.recent.trades as f64,
+                    comparison: Comparison::Less,
+                    bound: min_trades as f64,
+                    unit: "",
+                }),
+            );
+        }
+        let Some(ratio) = spike.ratio else {
+            return FilterOutcome::fail(
+                "insufficient_baseline",
+                Detail::Note(format!(
+                    "no swaps in the {}s before the last {}s",
+                    baseline.as_secs(),
+                    window.as_secs()
+                )),
+            );
+        };
+
+        let measured = |comparison| {
+            Detail::Measured(Measurement {
+                metric: "volume_spike",
+                value: ratio,
+                comparison,
+                bound: min_ratio,
+                unit: "x",
+            })
+        };
+        if ratio < min_ratio {
+            FilterOutcome::fail("below_spike_ratio", measured(Comparison::Less))
+        } else {
+            FilterOutcome::pass("spike_detected", measured(Comparison::GreaterOrEqual))
+        }
+    }
+}
+
+/// A trade's USD size and its share of market cap and liquidity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradeSize {
+    pub usd: f64,
+    pub market_cap_percent: Option<f64>,
+    pub liquidity_percent: Option<f64>,
+}
+
+impl TradeSize {
+    /// Sizes the event's trade at the current price, or the last recorded one.
+    pub fn measure(event: &EventContext<'_>) -> Option<Self> {
+        let trade = event.trade?;
+        let price = event
+            .metrics
+            .price_usd
+            .or_else(|| event.prices.latest(event.mint))?;
+        let usd = trade.tokens * price;
+        let share = |total: f64| (total > 0.0).then(|| usd / total * 100.0);
+        Some(Self {
+            usd,
+            market_cap_percent: share(event.market_cap),
+            liquidity_percent: event.metrics.liquidity_usd.and_then(share),
+        })
+    }
+}
+
+/// Flags a single swap above any configured size threshold.
+pub struct WhaleFilter {
+    settings: WhaleSettings,
+}
+
+impl WhaleFilter {
+    pub fn new(settings: WhaleSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl Filter for WhaleFilter {
+    fn name(&self) -> &'static str {
+        "whale"
+    }
+
+    fn evaluate(&self, event: &EventContext<'_>) -> FilterOutcome {
+        if event.trade.is_none() {
+            return FilterOutcome::fail(
+                "not_a_trade",
+                Detail::Note("no swap by the fee payer".to_string()),
+            );
+        }
+        let Some(size) = TradeSize::measure(event) else {
+            return FilterOutcome::fail(
+                "missing_price",
+                Detail::Note("no price to size the trade".to_string()),
+            );
+        };
+
+        let checks = [
+            ("trade_usd", self.settings.min_usd, Some(size.usd), ""),
+            (
+                "trade_market_cap_share",
+                self.settings.min_market_cap_percent,
+                size.market_cap_percent,
+                "%",
+            ),
+            (
+                "trade_liquidity_share",
+                self.settings.min_liquidity_percent,
+                size.liquidity_percent,
+                "%",
+            ),
+        ];
+        let mut shortfall = None;
+        for (metric, bound, value, unit) in checks {
+            let (Some(bound), Some(value)) = (bound, value) else {
+                continue;
+            };
+            let measured = |comparison| Measurement {
+                metric,
+                value,
+                comparison,
+                bound,
+                unit,
+            };
+            if value >= bound {
+                return FilterOutcome::pass(
+                    "whale_trade",
+                    Detail::Measured(measured(Comparison::GreaterOrEqual)),
+                );
+            }
+            shortfall.get_or_insert(measured(Comparison::Less));
+        }
+
+        let detail = match shortfall {
+            Some(measurement) => Detail::Measured(measurement),
+            None => Detail::Note("no whale threshold could be measured".to_string()),
+        };
+        FilterOutcome::fail("below_whale_size", detail)
+    }
+}
+
+/// Passes when a pump.fun curve crosses the fill threshold or migrates.
+pub struct CurveFilter {
+    settings: CurveSettings,
+}
+
+impl CurveFilter {
+    pub fn new(settings: CurveSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl Filter for CurveFilter {
+    fn name(&self) -> &'static str {
+        "curve"
+    }
+
+    fn evaluate(&self, event: &EventContext<'_>) -> FilterOutcome {
+        let progress = match event.curve {
+            None => {
+                return FilterOutcome::fail(
+                    "not_a_curve_event",
+                    Detail::Note("no pump.fun trade or migration".to_string()),
+                );
+            }
+            Some(CurveUpdate::Migrated { destination, .. }) => {
+                let note = Detail::Note(format!("migrated to {destination}"));
+                return if self.settings.migrated {
+                    FilterOutcome::pass("curve_migrated", note)
+                } else {
+                    FilterOutcome::fail("migration_not_watched", note)
+                };
+            }
+            Some(CurveUpdate::Progress {
+                fill_percent,
+                previous_percent,
+            }) => (*fill_percent, *previous_percent),
+        };
+
+        let (fill, previous) = progress;
+        let Some(bound) = self.settings.fill_percent else {
+            return FilterOutcome::fail(
+                "not_a_migration",
+                Detail::Note(format!("curve trade at {fill:.2}% fill")),
+            );
+        };
+        let measured = |comparison| {
+            Detail::Measured(Measurement {
+                metric: "curve_fill",
+                value: fill,
+                comparison,
+                bound,
+                unit: "%",
+            })
+        };
+        if fill < bound {
+            return FilterOutcome::fail("below_curve_fill", measured(Comparison::Less));
+        }
+        // Only the trade that crosses the threshold alerts, not every one after it.
+        match previous {
+            Some(previous) if previous >= bound => FilterOutcome::fail(
+                "curve_fill_already_passed",
+                Detail::Note(format!(
+                    "curve was already at {previous:.2}% before this trade"
+                )),
+            ),
+            _ => FilterOutcome::pass("curve_fill_crossed", measured(Comparison::GreaterOrEqual)),
+        }
+    }
+}
+
+/// Rug-risk checks; `block` actions fail the rule, `warn` actions annotate the alert.
+pub struct RiskFilter {
+    settings: RiskSettings,
+}
+
+impl RiskFilter {
+    pub fn new(settings: RiskSettings) -> Self {
+        Self { settings }
+    }
+
+    fn top10_action(&self) -> RiskAction {
+        match self.settings.top10_max_percent {
+            Some(_) => self.settings.top10_action,
+            None => RiskAction::Ignore,
+        }
+    }
+
+    /// Missing data fails only when some check would have blocked.
+    fn unknown(&self, what: &str) -> FilterOutcome {
+        let note = format!("{what} unavailable");
+        let blocking = [
+            self.settings.mint_authority,
+            self.settings.freeze_authority,
+            self.top10_action(),
+        ]
+        .contains(&RiskAction::Block);
+        if blocking {
+            FilterOutcome::fail("missing_risk_data", Detail::Note(note))
+        } else {
+            FilterOutcome::pass("risk_unchecked", Detail::Note(note.clone()))
+                .with_warnings(vec![note])
+        }
+    }
+}
+
+impl Filter for RiskFilter {
+    fn name(&self) -> &'static str {
+        "risk"
+    }
+
+    fn evaluate(&self, event: &EventContext<'_>) -> FilterOutcome {
+        let Some(report) = event.risk else {
+            return self.unknown("on-chain risk data");
+        };
+
+        let mut warnings = Vec::new();
+        let flags = [
+            (
+                self.settings.mint_authority,
+                report.mint_authority,
+                "mint_authority_enabled",
+                "mint authority enabled",
+            ),
+            (
+                self.settings.freeze_authority,
+                report.freeze_authority,
+                "freeze_authority_enabled",
+                "freeze authority enabled",
+            ),
+        ];
+        for (action, tripped, code, text) in flags {
+            match action {
+                _ if !tripped => {}
+                RiskAction::Block => {
+                    return FilterOutcome::fail(code, Detail::Note(text.to_string()));
+                }
+                RiskAction::Warn => warnings.push(text.to_string()),
+                RiskAction::Ignore => {}
+            }
+        }
+
+        if let (Some(max), action) = (self.settings.top10_max_percent, self.top10_action())
+            && action != RiskAction::Ignore
+        {
+            let Some(share) = report.top10_share_percent else {
+                return self.unknown("top-10 holder share");
+            };
+            if share > max {
+                let measurement = Measurement {
+                    metric: "top10_share",
+                    value: share,
+                    comparison: Comparison::Greater,
+                    bound: max,
+                    unit: "%",
+                };
+                if action == RiskAction::Block {
+                    return FilterOutcome::fail(
+                        "top10_concentration",
+                        Detail::Measured(measurement),
+                    );
+                }
+                warnings.push(measurement.to_string());
+            }
+        }
+
+        FilterOutcome::pass("risk_accepted", Detail::Note(report.summary())).with_warnings(warnings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Bounds, Comparison, CurveFilter, Detail, EventContext, Filter, FilterOutcome,
+        FilterPipeline, GrowthFilter, Measurement, RangeFilter, RiskFilter, VolumeSpikeFilter,
+        WhaleFilter, all_passed,
+    };
+    use crate::config::{
+        CurveSettings, GrowthSettings, RiskAction, RiskSettings, VolumeSpikeSettings, WhaleSettings,
+    };
+    use crate::growth::PriceHistory;
+    use crate::helius::{Trade, TradeDirection};
+    use crate::moralis::TokenMetrics;
+    use crate::pumpfun::CurveUpdate;
+    use crate::risk::RiskReport;
+    use crate::volume::TradeVolumes;
+    use std::{sync::LazyLock, time::Duration};
+
+    /// An event for `mint` with `metrics` and nothing else known; tests set the rest.
+    fn context(metrics: &TokenMetrics) -> EventContext<'_> {
+        static PRICES: LazyLock<PriceHistory> = LazyLock::new(|| PriceHistory::new(Duration::ZERO));
+        static VOLUMES: LazyLock<TradeVolumes> =
+            LazyLock::new(|| TradeVolumes::new(Duration::ZERO));
+        EventContext {
+            mint: "mint",
+            market_cap: 0.0,
+            metrics,
+            prices: &PRICES,
+            volumes: &VOLUMES,
+            trade: None,
+            curve: None,
+            risk: None,
+        }
+    }
+
+    fn evaluate(filter: &RangeFilter, market_cap: f64) -> FilterOutcome {
+        filter.evaluate(&EventContext {
+            market_cap,
+            ..context(&TokenMetrics::default())
+        })
+    }
+
+    #[test]
+    fn passes_when_within_range() {
+        let filter = RangeFilter::market_cap(Bounds {
+            min: Some(1_000.0),
+            max: Some(5_000_000.0),
+        });
+
+        assert!(evaluate(&filter, 10_000.0).passed);
+        assert!(evaluate(&filter, 1_000.0).passed);
+        assert!(evaluate(&filter, 5_000_000.0).passed);
+    }
+
+    #[test]
+    fn blocks_below_min() {
+        let filter = RangeFilter::market_cap(Bounds {
+            min: Some(10_000.0),
+            max: Some(100_000.0),
+        });
+
+        let outcome = evaluate(&filter, 9_999.99);
+        assert!(!outcome.passed);
+        assert_eq!("out_of_cap_range", outcome.code);
+        assert_eq!("market_cap 9999.99 < 10000", outcome.reason());
+    }
+
+    #[test]
+    fn blocks_above_max() {
+        let filter = RangeFilter::market_cap(Bounds {
+            min: Some(10_000.0),
+            max: Some(100_000.0),
+        });
+
+        let outcome = evaluate(&filter, 100_000.01);
+        assert_eq!(
+            Some(&Measurement {
+                metric: "market_cap",
+                value: 100_000.01,
+                comparison: Comparison::Greater,
+                bound: 100_000.0,
+                unit: "",
+            }),
+            outcome.measurement()
+        );
+        assert_eq!("market_cap 100000.01 > 100000", outcome.reason());
+    }
+
+    #[test]
+    fn open_lower_bound_allows_anything_below_max() {
+        let filter = RangeFilter::market_cap(Bounds {
+            min: None,
+            max: Some(1_000_000.0),
+        });
+
+        assert!(evaluate(&filter, 0.0).passed);
+        assert!(evaluate(&filter, 999_999.99).passed);
+    }
+
+    #[test]
+    fn open_upper_bound_allows_anything_above_min() {
+        let filter = RangeFilter::market_cap(Bounds {
+            min: Some(5_000.0),
+            max: None,
+        });
+
+        assert!(!evaluate(&filter, 4_999.99).passed);
+        assert!(evaluate(&filter, 5_000.0).passed);
+        assert!(evaluate(&filter, 50_000_000.0).passed);
+    }
+
+    struct Fixed(&'static str, bool);
+
+    impl Filter for Fixed {
+        fn name(&self) -> &'static str {
+            self.0
+        }
+
+        fn evaluate(&self, _event: &EventContext<'_>) -> FilterOutcome {
+            if self.1 {
+                FilterOutcome::pass("ok", Detail::Note(String::new()))
+            } else {
+                FilterOutcome::fail("nope", Detail::Note(String::new()))
+            }
+        }
+    }
+
+    #[test]
+    fn pipeline_records_verdicts_until_first_failure() {
+        let pipeline = FilterPipeline::new(vec![
+            Box::new(Fixed("first", true)),
+            Box::new(Fixed("second", false)),
+            Box::new(Fixed("third", true)),
+        ]);
+        let verdicts = pipeline.run(&context(&TokenMetrics::default()));
+
+        let names: Vec<&str> = verdicts.iter().map(|verdict| verdict.filter).collect();
+        assert_eq!(vec!["first", "second"], names);
+        assert!(!all_passed(&verdicts));
+    }
+
+    #[test]
+    fn growth_reason_reports_value_and_threshold() {
+        let filter = GrowthFilter::new(GrowthSettings {
+            percent: 15.0,
+            window: Duration::from_secs(300),
+        });
+        let prices = PriceHistory::new(Duration::from_secs(300));
+        let metrics = TokenMetrics::default();
+        let event = EventContext {
+            prices: &prices,
+            ..context(&metrics)
+        };
+
+        let outcome = filter.evaluate(&event);
+        assert_eq!("insufficient_history", outcome.code);
+        assert_eq!("fewer than 2 price samples in 300s", outcome.reason());
+
+        prices.record("mint", 1.0);
+        prices.record("mint", 1.1);
+        let outcome = filter.evaluate(&event);
+        assert_eq!("below_threshold", outcome.code);
+        assert_eq!("growth 10.00% < 15%", outcome.reason());
+    }
+
+    #[test]
+    fn missing_liquidity_fails_and_holders_are_checked() {
+        let mut metrics = TokenMetrics::default();
+        let liquidity = RangeFilter::liquidity(Bounds {
+            min: Some(20_000.0),
+            max: None,
+        });
+        let holders = RangeFilter::holders(Bounds {
+            min: Some(100.0),
+            max: Some(5_000.0),
+        });
+
+        assert_eq!(
+            "missing_liquidity",
+            liquidity.evaluate(&context(&metrics)).code
+        );
+
+        metrics.liquidity_usd = Some(12_500.5);
+        metrics.holders = Some(6_000);
+        let event = context(&metrics);
+        assert_eq!(
+            "liquidity 12500.50 < 20000",
+            liquidity.evaluate(&event).reason()
+        );
+        let outcome = holders.evaluate(&event);
+        assert_eq!("out_of_holder_range", outcome.code);
+        assert_eq!("holders 6000 > 5000", outcome.reason());
+    }
+
+    #[test]
+    fn age_filter_compares_seconds_since_creation() {
+        let created_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("clock after epoch")
+            .as_secs() as i64
+            - 7_200;
+        let metrics = TokenMetrics {
+            created_at: Some(created_at),
+            ..TokenMetrics::default()
+        };
+        let event = context(&metrics);
+
+        let fresh_only = RangeFilter::age(Bounds {
+            min: None,
+            max: Some(3_600.0),
+        });
+        let outcome = fresh_only.evaluate(&event);
+        assert_eq!("out_of_age_range", outcome.code);
+        assert!(
+            outcome.reason().ends_with("s > 3600s"),
+            "{}",
+            outcome.reason()
+        );
+
+        let unknown = TokenMetrics::default();
+        assert_eq!(
+            "missing_creation_time",
+            fresh_only.evaluate(&context(&unknown)).code
+        );
+    }
+
+    #[test]
+    fn risk_filter_blocks_or_warns_per_action() {
+        let metrics = TokenMetrics::default();
+        let report = RiskReport {
+            mint_authority: true,
+            freeze_authority: false,
+            top10_share_percent: Some(72.5),
+        };
+        let event = EventContext {
+            risk: Some(&report),
+            ..context(&metrics)
+        };
+
+        let strict = RiskFilter::new(RiskSettings {
+            mint_authority: RiskAction::Block,
+            ..RiskSettings::default()
+        });
+        assert_eq!("mint_authority_enabled", strict.evaluate(&event).code);
+
+        let lenient = RiskFilter::new(RiskSettings {
+            mint_authority: RiskAction::Warn,
+            freeze_authority: RiskAction::Block,
+            top10_max_percent: Some(50.0),
+            top10_action: RiskAction::Warn,
+        });
+        let outcome = lenient.evaluate(&event);
+        assert!(outcome.passed);
+        assert_eq!(
+            vec![
+                "mint authority enabled".to_string(),
+                "top10_share 72.50% > 50%".to_string()
+            ],
+            outcome.warnings
+        );
+
+        let concentrated = RiskFilter::new(RiskSettings {
+            top10_max_percent: Some(50.0),
+            top10_action: RiskAction::Block,
+            ..RiskSettings::default()
+        });
+        assert_eq!("top10_concentration", concentrated.evaluate(&event).code);
+        assert_eq!(
+            "missing_risk_data",
+            concentrated.evaluate(&context(&metrics)).code
+        );
+    }
+
+    #[test]
+    fn volume_spike_needs_trades_and_a_baseline() {
+        let volumes = TradeVolumes::new(Duration::from_secs(660));
+        let filter = VolumeSpikeFilter::new(VolumeSpikeSettings {
+            window: Duration::from_secs(60),
+            baseline: Duration::from_secs(600),
+            min_ratio: 5.0,
+            min_trades: 2,
+        });
+        let metrics = TokenMetrics::default();
+        let event = EventContext {
+            volumes: &volumes,
+            ..context(&metrics)
+        };
+
+        volumes.record("mint", 10.0);
+        let outcome = filter.evaluate(&event);
+        assert_eq!("too_few_trades", outcome.code);
+        assert_eq!("trades 1 < 2", outcome.reason());
+
+        volumes.record("mint", 10.0);
+        assert_eq!("insufficient_baseline", filter.evaluate(&event).code);
+    }
+
+    #[test]
+    fn whale_filter_passes_on_any_threshold() {
+        let metrics = TokenMetrics {
+            price_usd: Some(0.5),
+            liquidity_usd: Some(40_000.0),
+            ..TokenMetrics::default()
+        };
+        let trade = Trade {
+            wallet: "Whale111".to_string(),
+            direction: TradeDirection::Buy,
+            tokens: 10_000.0,
+        };
+        let event = EventContext {
+            market_cap: 1_000_000.0,
+            trade: Some(&trade),
+            ..context(&metrics)
+        };
+
+        let by_usd = WhaleFilter::new(WhaleSettings {
+            min_usd: Some(25_000.0),
+            ..WhaleSettings::default()
+        });
+        let outcome = by_usd.evaluate(&event);
+        assert_eq!("below_whale_size", outcome.code);
+        assert_eq!("trade_usd 5000 < 25000", outcome.reason());
+
+        let by_liquidity = WhaleFilter::new(WhaleSettings {
+            min_usd: Some(25_000.0),
+            min_liquidity_percent: Some(10.0),
+            ..WhaleSettings::default()
+        });
+        let outcome = by_liquidity.evaluate(&event);
+        assert_eq!("whale_trade", outcome.code);
+        assert_eq!("trade_liquidity_share 12.50% >= 10%", outcome.reason());
+
+        assert_eq!("not_a_trade", by_usd.evaluate(&context(&metrics)).code);
+    }
+
+    #[test]
+    fn curve_filter_alerts_once_per_crossing_and_on_migration() {
+        let metrics = TokenMetrics::default();
+        let filter = CurveFilter::new(CurveSettings {
+            fill_percent: Some(90.0),
+            migrated: true,
+        });
+        let evaluate = |curve: CurveUpdate| {
+            filter.evaluate(&EventContext {
+                curve: Some(&curve),
+                ..context(&metrics)
+            })
+        };
+        let progress = |fill_percent, previous_percent| CurveUpdate::Progress {
+            fill_percent,
+            previous_percent,
+        };
+
+        let outcome = evaluate(progress(85.0, Some(80.0)));
+        assert_eq!("below_curve_fill", outcome.code);
+        assert_eq!("curve_fill 85% < 90%", outcome.reason());
+        assert_eq!(
+            "curve_fill_crossed",
+            evaluate(progress(91.5, Some(88.0))).code
+        );
+        assert_eq!("curve_fill_crossed", evaluate(progress(95.0, None)).code);
+        assert_eq!(
+            "curve_fill_already_passed",
+            evaluate(progress(96.0, Some(95.0))).code
+        );
+        assert_eq!(
+            "curve_migrated",
+            evaluate(CurveUpdate::Migrated {
+                destination: "Raydium",
+                last_percent: None,
+            })
+            .code
+        );
+    }
+}
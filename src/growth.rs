@@ -0,0 +1,157 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// How often mints without recent samples are dropped from the history.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Recent USD prices per mint, used to measure growth over a profile's window.
+#[derive(Clone)]
+pub struct PriceHistory {
+    inner: Arc<Mutex<Samples>>,
+}
+
+struct Samples {
+    retention: Duration,
+    by_mint: HashMap<String, VecDeque<(Instant, f64)>>,
+    last_sweep: Instant,
+}
+
+impl PriceHistory {
+    /// Keeps samples for `retention`, which should cover the longest growth window.
+    pub fn new(retention: Duration) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Samples {
+                retention,
+                by_mint: HashMap::new(),
+                last_sweep: Instant::now(),
+            })),
+        }
+    }
+
+    pub fn set_retention(&self, retention: Duration) {
+        self.inner.lock().expect("price history poisoned").retention = retention;
+    }
+
+    pub fn record(&self, mint: &str, price: f64) {
+        self.record_at(mint, price, Instant::now());
+    }
+
+    /// Percent change from the lowest price within `window` to the latest one.
+    ///
+    /// Returns `None` until at least two samples fall inside the window.
+    pub fn growth_percent(&self, mint: &str, window: Duration) -> Option<f64> {
+        self.growth_percent_at(mint, window, Instant::now())
+    }
+
+    /// Most recent price still within retention.
+    pub fn latest(&self, mint: &str) -> Option<f64> {
+        self.latest_at(mint, Instant::now())
+    }
+
+    fn record_at(&self, mint: &str, price: f64, now: Instant) {
+        if !price.is_finite() || price <= 0.0 {
+            return;
+        }
+
+        let mut inner = self.inner.lock().expect("price history poisoned");
+        let retention = inner.retention;
+        if now.duration_since(inner.last_sweep) >= SWEEP_INTERVAL {
+            inner.by_mint.retain(|_, samples| {
+                samples
+                    .back()
+                    .is_some_and(|(at, _)| now.duration_since(*at) < retention)
+            });
+            inner.last_sweep = now;
+        }
+
+        let samples = inner.by_mint.entry(mint.to_string()).or_default();
+        while samples
+            .front()
+            .is_some_and(|(at, _)| now.duration_since(*at) >= retention)
+        {
+            samples.pop_front();
+        }
+        samples.push_back((now, price));
+    }
+
+    fn latest_at(&self, mint: &str, now: Instant) -> Option<f64> {
+        let inner = self.inner.lock().expect("price history poisoned");
+        let (at, price) = inner.by_mint.get(mint)?.back()?;
+        (now.duration_since(*at) < inner.retention).then_some(*price)
+    }
+
+    fn growth_percent_at(&self, mint: &str, window: Duration, now: Instant) -> Option<f64> {
+        let inner = self.inner.lock().expect("price history poisoned");
+        let samples = inner.by_mint.get(mint)?;
+        let in_window: Vec<f64> = samples
+            .iter()
+            .filter(|(at, _)| now.duration_since(*at) <= window)
+            .map(|(_, price)| *price)
+            .collect();
+        if in_window.len() < 2 {
+            return None;
+        }
+
+        let latest = *in_window.last()?;
+        let lowest = in_window.iter().copied().fold(f64::INFINITY, f64::min);
+        Some((latest / lowest - 1.0) * 100.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PriceHistory;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn measures_growth_from_lowest_price_in_window() {
+        let history = PriceHistory::new(Duration::from_secs(600));
+        let start = Instant::now();
+
+        history.record_at("mint", 2.0, start);
+        history.record_at("mint", 1.0, start + Duration::from_secs(100));
+        history.record_at("mint", 1.5, start + Duration::from_secs(200));
+
+        let now = start + Duration::from_secs(200);
+        let growth = history
+            .growth_percent_at("mint", Duration::from_secs(300), now)
+            .expect("growth");
+        assert!((growth - 50.0).abs() < 1e-9);
+
+        // Only the latest sample remains inside a 50 s window.
+        assert_eq!(
+            None,
+            history.growth_percent_at("mint", Duration::from_secs(50), now)
+        );
+    }
+
+    #[test]
+    fn drops_samples_older_than_retention() {
+        let history = PriceHistory::new(Duration::from_secs(60));
+        let start = Instant::now();
+
+        history.record_at("mint", 1.0, start);
+        history.record_at("mint", 3.0, start + Duration::from_secs(90));
+        history.record_at("mint", 3.3, start + Duration::from_secs(100));
+
+        let growth = history
+            .growth_percent_at(
+                "mint",
+                Duration::from_secs(600),
+                start + Duration::from_secs(100),
+            )
+            .expect("growth");
+        assert!((growth - 10.0).abs() < 1e-9);
+        assert_eq!(
+            Some(3.3),
+            history.latest_at("mint", start + Duration::from_secs(150))
+        );
+        assert_eq!(
+            None,
+            history.latest_at("mint", start + Duration::from_secs(160))
+        );
+    }
+}
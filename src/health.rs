@@ -0,0 +1,181 @@
+use axum::{Json, extract::State, http::StatusCode};
+use serde::Serialize;
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use crate::server::AppState;
+
+/// Shared readiness signals updated by startup probes and live traffic.
+#[derive(Clone)]
+pub struct Readiness {
+    telegram_ok: Arc<AtomicBool>,
+    price_provider_ok: Arc<AtomicBool>,
+    in_flight: Arc<AtomicUsize>,
+    max_in_flight: usize,
+}
+
+impl Readiness {
+    pub fn new(max_in_flight: usize) -> Self {
+        Self {
+            telegram_ok: Arc::new(AtomicBool::new(false)),
+            price_provider_ok: Arc::new(AtomicBool::new(false)),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            max_in_flight,
+        }
+    }
+
+    pub fn set_telegram(&self, ok: bool) {
+        self.telegram_ok.store(ok, Ordering::Relaxed);
+    }
+
+    pub fn set_price_provider(&self, ok: bool) {
+        self.price_provider_ok.store(ok, Ordering::Relaxed);
+    }
+
+    pub fn telegram_ok(&self) -> bool {
+        self.telegram_ok.load(Ordering::Relaxed)
+    }
+
+    pub fn price_provider_ok(&self) -> bool {
+        self.price_provider_ok.load(Ordering::Relaxed)
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::Relaxed)
+    }
+
+    /// Reserves a slot for one event being analyzed, or `None` when the queue is saturated.
+    pub fn try_acquire(&self) -> Option<InFlightGuard> {
+        self.in_flight
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |current| {
+                (current < self.max_in_flight).then_some(current + 1)
+            })
+            .ok()
+            .map(|_| InFlightGuard {
+                in_flight: self.in_flight.clone(),
+            })
+    }
+
+    fn report(&self) -> ReadinessReport {
+        let in_flight = self.in_flight();
+        let queue_ok = in_flight < self.max_in_flight;
+        let telegram = self.telegram_ok();
+        let price_provider = self.price_provider_ok();
+
+        ReadinessReport {
+            ready: telegram && price_provider && queue_ok,
+            checks: ReadinessChecks {
+                config_loaded: true,
+                telegram,
+                price_provider,
+                queue: QueueCheck {
+                    ok: queue_ok,
+                    in_flight,
+                    max_in_flight: self.max_in_flight,
+                },
+            },
+        }
+    }
+}
+
+/// Releases the in-flight slot when the analyzer task finishes.
+pub struct InFlightGuard {
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.in_flight.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+#[derive(Serialize)]
+pub struct ReadinessReport {
+    ready: bool,
+    checks: ReadinessChecks,
+}
+
+#[derive(Serialize)]
+pub struct ReadinessChecks {
+    config_loaded: bool,
+    telegram: bool,
+    price_provider: bool,
+    queue: QueueCheck,
+}
+
+#[derive(Serialize)]
+pub struct QueueCheck {
+    ok: bool,
+    in_flight: usize,
+    max_in_flight: usize,
+}
+
+#[derive(Serialize)]
+pub struct StatusBody {
+    status: &'static str,
+}
+
+#[derive(Serialize)]
+pub struct VersionBody {
+    name: &'static str,
+    version: &'static str,
+    git_sha: Option<&'static str>,
+}
+
+pub async fn healthz_handler() -> Json<StatusBody> {
+    Json(StatusBody { status: "ok" })
+}
+
+pub async fn readyz_handler(
+    State(state): State<Arc<AppState>>,
+) -> (StatusCode, Json<ReadinessReport>) {
+    let report = state.readiness.report();
+    let status = if report.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (status, Json(report))
+}
+
+pub async fn version_handler() -> Json<VersionBody> {
+    Json(VersionBody {
+        name: env!("CARGO_PKG_NAME"),
+        version: env!("CARGO_PKG_VERSION"),
+        git_sha: option_env!("GIT_SHA"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Readiness;
+
+    #[test]
+    fn queue_saturates_at_max_in_flight() {
+        let readiness = Readiness::new(2);
+
+        let first = readiness.try_acquire().expect("first slot");
+        let _second = readiness.try_acquire().expect("second slot");
+        assert!(readiness.try_acquire().is_none());
+        assert!(!readiness.report().checks.queue.ok);
+
+        drop(first);
+        assert_eq!(1, readiness.in_flight());
+        assert!(readiness.try_acquire().is_some());
+    }
+
+    #[test]
+    fn ready_only_when_all_checks_pass() {
+        let readiness = Readiness::new(1);
+        assert!(!readiness.report().ready);
+
+        readiness.set_telegram(true);
+        assert!(!readiness.report().ready);
+
+        readiness.set_price_provider(true);
+        assert!(readiness.report().ready);
+    }
+}
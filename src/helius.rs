@@ -1,11 +1,49 @@
 use serde::Deserialize;
 
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct HeliusWebhook {
     pub signature: String,
+    /// Helius transaction type, e.g. `SWAP` or `TRANSFER`.
+    #[serde(default, rename = "type")]
+    pub event_type: Option<String>,
+    /// Program Helius attributes the transaction to, e.g. `RAYDIUM` or `PUMP_FUN`.
+    #[serde(default)]
+    pub source: Option<String>,
+    /// Wallet that signed and paid for the transaction.
+    #[serde(default)]
+    pub fee_payer: Option<String>,
+    #[serde(default)]
+    pub slot: Option<u64>,
+    /// Block time in Unix seconds.
+    #[serde(default)]
+    pub timestamp: Option<i64>,
     #[serde(default)]
     pub events: HeliusEvents,
+    #[serde(default)]
+    pub instructions: Vec<Instruction>,
+}
+
+/// A top-level instruction; inner instructions use the same shape without nesting.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Instruction {
+    pub program_id: String,
+    #[serde(default)]
+    pub accounts: Vec<String>,
+    /// Instruction data, base58 encoded.
+    #[serde(default)]
+    pub data: String,
+    #[serde(default)]
+    pub inner_instructions: Vec<Instruction>,
+}
+
+impl Instruction {
+    pub fn data_bytes(&self) -> Option<Vec<u8>> {
+        decode_base58(&self.data)
+    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize)]
@@ -17,7 +55,6 @@ pub struct HeliusEvents {
 
 #[derive(Clone, Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-#[allow(dead_code)]
 pub struct TokenTransfer {
     pub mint: String,
     #[serde(default)]
@@ -35,4 +72,186 @@ impl HeliusWebhook {
             .first()
             .map(|transfer| transfer.mint.as_str())
     }
+
+    /// Tokens of `mint` moved by a `SWAP` event; the largest leg when routed over several hops.
+    pub fn swap_amount(&self, mint: &str) -> Option<f64> {
+        if !self.is_swap() {
+            return None;
+        }
+        self.events
+            .token_transfers
+            .iter()
+            .filter(|transfer| transfer.mint == mint)
+            .filter_map(|transfer| transfer.token_amount)
+            .reduce(f64::max)
+    }
+
+    /// The fee payer's net position change in `mint` from a `SWAP` event.
+    pub fn trade(&self, mint: &str) -> Option<Trade> {
+        if !self.is_swap() {
+            return None;
+        }
+        let wallet = self.fee_payer.as_deref()?;
+        let net: f64 = self
+            .events
+            .token_transfers
+            .iter()
+            .filter(|transfer| transfer.mint == mint)
+            .map(|transfer| {
+                let amount = transfer.token_amount.unwrap_or(0.0);
+                if transfer.to_user_account.as_deref() == Some(wallet) {
+                    amount
+                } else if transfer.from_user_account.as_deref() == Some(wallet) {
+                    -amount
+                } else {
+                    0.0
+                }
+            })
+            .sum();
+        if net == 0.0 || !net.is_finite() {
+            return None;
+        }
+
+        Some(Trade {
+            wallet: wallet.to_string(),
+            direction: if net > 0.0 {
+                TradeDirection::Buy
+            } else {
+                TradeDirection::Sell
+            },
+            tokens: net.abs(),
+        })
+    }
+
+    /// Every instruction, each top-level one followed by its inner instructions.
+    pub fn all_instructions(&self) -> impl Iterator<Item = &Instruction> {
+        self.instructions.iter().flat_map(|instruction| {
+            std::iter::once(instruction).chain(&instruction.inner_instructions)
+        })
+    }
+
+    fn is_swap(&self) -> bool {
+        self.event_type
+            .as_deref()
+            .is_some_and(|event_type| event_type.eq_ignore_ascii_case("SWAP"))
+    }
+}
+
+/// One wallet buying or selling a token in a swap.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trade {
+    pub wallet: String,
+    pub direction: TradeDirection,
+    pub tokens: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TradeDirection {
+    Buy,
+    Sell,
+}
+
+impl TradeDirection {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TradeDirection::Buy => "buy",
+            TradeDirection::Sell => "sell",
+        }
+    }
+}
+
+/// Decodes a base58 string as used for Solana addresses and instruction data.
+pub fn decode_base58(value: &str) -> Option<Vec<u8>> {
+    // Little-endian base-256 digits of the number, grown as characters are folded in.
+    let mut bytes: Vec<u8> = Vec::with_capacity(value.len());
+    for c in value.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&digit| digit == c)? as u32;
+        for byte in &mut bytes {
+            carry += u32::from(*byte) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+    // Each leading `1` encodes a leading zero byte.
+    let zeros = value.bytes().take_while(|&c| c == b'1').count();
+    bytes.extend(std::iter::repeat_n(0, zeros));
+    bytes.reverse();
+    Some(bytes)
+}
+
+/// Encodes bytes as base58, the inverse of [`decode_base58`].
+pub fn encode_base58(bytes: &[u8]) -> String {
+    // Little-endian base-58 digits, the mirror image of decoding.
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 2);
+    for &byte in bytes {
+        let mut carry = u32::from(byte);
+        for digit in &mut digits {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+    std::iter::repeat_n(b'1', zeros)
+        .chain(
+            digits
+                .iter()
+                .rev()
+                .map(|&digit| BASE58_ALPHABET[digit as usize]),
+        )
+        .map(char::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HeliusWebhook, TradeDirection, decode_base58, encode_base58};
+
+    #[test]
+    fn round_trips_base58() {
+        assert_eq!(Some(vec![1]), decode_base58("2"));
+        assert_eq!(Some(vec![0, 0, 57]), decode_base58("11z"));
+        assert_eq!(
+            Some(32),
+            decode_base58("So11111111111111111111111111111111111111112").map(|bytes| bytes.len())
+        );
+        assert_eq!(None, decode_base58("0OIl"));
+        assert_eq!("11z", encode_base58(&[0, 0, 57]));
+        let wsol = "So11111111111111111111111111111111111111112";
+        assert_eq!(wsol, encode_base58(&decode_base58(wsol).expect("decode")));
+    }
+
+    #[test]
+    fn fee_payer_net_transfer_gives_trade_direction() {
+        let event: HeliusWebhook = serde_json::from_value(serde_json::json!({
+            "signature": "sig",
+            "type": "SWAP",
+            "feePayer": "Whale111",
+            "events": {"tokenTransfers": [
+                {"mint": "So11111111111111111111111111111111111111112", "fromUserAccount": "Whale111", "toUserAccount": "Pool111", "tokenAmount": 50.0},
+                {"mint": "Mint111", "fromUserAccount": "Pool111", "toUserAccount": "Whale111", "tokenAmount": 1200.5}
+            ]}
+        }))
+        .expect("webhook");
+
+        let trade = event.trade("Mint111").expect("trade");
+        assert_eq!("Whale111", trade.wallet);
+        assert_eq!(TradeDirection::Buy, trade.direction);
+        assert_eq!(1200.5, trade.tokens);
+        assert_eq!(
+            TradeDirection::Sell,
+            event
+                .trade("So11111111111111111111111111111111111111112")
+                .expect("sell leg")
+                .direction
+        );
+    }
 }
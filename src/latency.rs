@@ -0,0 +1,112 @@
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Timestamps collected while one event moves from the chain to Telegram.
+///
+/// Chain time comes from the Helius `timestamp` (block time, whole seconds), so the
+/// chain-to-intake figure has one-second resolution; the other stages use a monotonic clock.
+#[derive(Clone, Copy, Debug)]
+pub struct LatencyTrace {
+    received_at: Instant,
+    chain_to_intake: Option<Duration>,
+    decided_at: Option<Instant>,
+}
+
+impl LatencyTrace {
+    /// Starts a trace at webhook receipt for an event with the given block time.
+    pub fn start(block_time: Option<i64>) -> Self {
+        Self::start_at(block_time, Instant::now(), SystemTime::now())
+    }
+
+    fn start_at(block_time: Option<i64>, received_at: Instant, wall_clock: SystemTime) -> Self {
+        let chain_to_intake = block_time.and_then(|block_time| {
+            let block_time = UNIX_EPOCH + Duration::from_secs(u64::try_from(block_time).ok()?);
+            // A block time ahead of our clock means skew, not negative latency.
+            Some(wall_clock.duration_since(block_time).unwrap_or_default())
+        });
+
+        Self {
+            received_at,
+            chain_to_intake,
+            decided_at: None,
+        }
+    }
+
+    pub fn received_at(&self) -> Instant {
+        self.received_at
+    }
+
+    /// Marks the moment the filter verdict was reached.
+    pub fn mark_decision(&mut self) {
+        self.decided_at = Some(Instant::now());
+    }
+
+    pub fn chain_to_intake(&self) -> Option<Duration> {
+        self.chain_to_intake
+    }
+
+    pub fn chain_to_intake_ms(&self) -> Option<u64> {
+        self.chain_to_intake.map(as_millis)
+    }
+
+    pub fn intake_to_decision_ms(&self) -> Option<u64> {
+        self.decided_at
+            .map(|decided_at| as_millis(decided_at.duration_since(self.received_at)))
+    }
+
+    pub fn decision_to_delivery_ms(&self, delivered_at: Instant) -> Option<u64> {
+        self.decided_at
+            .map(|decided_at| as_millis(delivered_at.duration_since(decided_at)))
+    }
+
+    /// One-line summary appended to alerts when the latency footer is enabled.
+    pub fn footer(&self) -> String {
+        let format_ms = |value: Option<u64>| {
+            value
+                .map(|ms| format!("{ms} ms"))
+                .unwrap_or_else(|| "n/a".to_string())
+        };
+
+        format!(
+            "Latency: chain→intake {}, intake→decision {}",
+            format_ms(self.chain_to_intake_ms()),
+            format_ms(self.intake_to_decision_ms())
+        )
+    }
+}
+
+fn as_millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LatencyTrace;
+    use std::time::{Duration, Instant, UNIX_EPOCH};
+
+    #[test]
+    fn chain_to_intake_measured_from_block_time() {
+        let wall_clock = UNIX_EPOCH + Duration::from_millis(1_700_000_002_500);
+        let trace = LatencyTrace::start_at(Some(1_700_000_000), Instant::now(), wall_clock);
+
+        assert_eq!(Some(2_500), trace.chain_to_intake_ms());
+    }
+
+    #[test]
+    fn future_block_time_clamps_to_zero() {
+        let wall_clock = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let trace = LatencyTrace::start_at(Some(1_700_000_005), Instant::now(), wall_clock);
+
+        assert_eq!(Some(0), trace.chain_to_intake_ms());
+    }
+
+    #[test]
+    fn footer_marks_missing_stages() {
+        let trace = LatencyTrace::start_at(None, Instant::now(), UNIX_EPOCH);
+
+        assert_eq!(
+            "Latency: chain→intake n/a, intake→decision n/a",
+            trace.footer()
+        );
+        assert_eq!(None, trace.decision_to_delivery_ms(Instant::now()));
+    }
+}
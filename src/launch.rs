@@ -0,0 +1,317 @@
+use crate::{
+    helius::{HeliusWebhook, Instruction},
+    pumpfun::PUMP_FUN_PROGRAM,
+};
+
+const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+const USDT_MINT: &str = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB";
+
+/// Instructions that create a pool or a token, with the account positions of the
+/// pool (or bonding curve) and the mints, as laid out by each program's IDL.
+const LAUNCH_INSTRUCTIONS: &[LaunchInstruction] = &[
+    LaunchInstruction {
+        program_id: "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8",
+        venue: "Raydium AMM v4",
+        kind: LaunchKind::PoolCreated,
+        // `initialize2`
+        discriminator: &[1],
+        pool: 4,
+        mints: &[8, 9],
+    },
+    LaunchInstruction {
+        program_id: "CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C",
+        venue: "Raydium CPMM",
+        kind: LaunchKind::PoolCreated,
+        // Anchor `initialize`
+        discriminator: &[175, 175, 109, 31, 13, 152, 155, 237],
+        pool: 3,
+        mints: &[4, 5],
+    },
+    LaunchInstruction {
+        program_id: "LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo",
+        venue: "Meteora DLMM",
+        kind: LaunchKind::PoolCreated,
+        // Anchor `initialize_lb_pair`
+        discriminator: &[45, 154, 237, 210, 221, 15, 166, 92],
+        pool: 0,
+        mints: &[2, 3],
+    },
+    LaunchInstruction {
+        program_id: "Eo7WjKq67rjJQSZxS6z3YkapzY3eMj6Xy8X5EQVn5UaB",
+        venue: "Meteora Dynamic AMM",
+        kind: LaunchKind::PoolCreated,
+        // Anchor `initialize_permissionless_pool`
+        discriminator: &[118, 173, 41, 157, 173, 72, 97, 103],
+        pool: 0,
+        mints: &[2, 3],
+    },
+    LaunchInstruction {
+        program_id: PUMP_FUN_PROGRAM,
+        venue: "pump.fun",
+        kind: LaunchKind::TokenCreated,
+        // Anchor `create`; the "pool" is the bonding curve account.
+        discriminator: &[24, 30, 200, 40, 5, 28, 7, 119],
+        pool: 2,
+        mints: &[0],
+    },
+];
+
+struct LaunchInstruction {
+    program_id: &'static str,
+    venue: &'static str,
+    kind: LaunchKind,
+    discriminator: &'static [u8],
+    pool: usize,
+    mints: &'static [usize],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaunchKind {
+    PoolCreated,
+    TokenCreated,
+}
+
+impl LaunchKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LaunchKind::PoolCreated => "pool_created",
+            LaunchKind::TokenCreated => "token_created",
+        }
+    }
+}
+
+/// A new pool or token found in a transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Launch {
+    pub kind: LaunchKind,
+    /// Program or Helius source, e.g. `Raydium CPMM` or `METEORA`.
+    pub venue: String,
+    /// The launched token.
+    pub base_mint: String,
+    /// SOL, USDC or USDT side of a new pool, when there is one.
+    pub quote_mint: Option<String>,
+    /// Pool or bonding curve account.
+    pub pool: Option<String>,
+    /// Tokens of each side the creator deposited in this transaction.
+    pub base_tokens: Option<f64>,
+    pub quote_tokens: Option<f64>,
+}
+
+impl Launch {
+    /// USD price of the quote mint when it is a stablecoin.
+    pub fn stable_quote_price(&self) -> Option<f64> {
+        self.quote_mint
+            .as_deref()
+            .filter(|mint| [USDC_MINT, USDT_MINT].contains(mint))
+            .map(|_| 1.0)
+    }
+
+    /// Both sides of the initial deposit in USD, valued at the quote side.
+    pub fn initial_liquidity_usd(&self, quote_price: f64) -> Option<f64> {
+        let usd = self.quote_tokens? * quote_price * 2.0;
+        (usd.is_finite() && usd > 0.0).then_some(usd)
+    }
+
+    /// Opening price of the base token implied by the deposit ratio.
+    pub fn initial_price_usd(&self, quote_price: f64) -> Option<f64> {
+        let price = self.quote_tokens? * quote_price / self.base_tokens?;
+        (price.is_finite() && price > 0.0).then_some(price)
+    }
+}
+
+/// Recognizes pool and token creation from known program instructions, falling
+/// back to the Helius transaction type and source.
+pub fn detect(event: &HeliusWebhook) -> Option<Launch> {
+    let (kind, venue, pool, mints) = event
+        .all_instructions()
+        .find_map(match_instruction)
+        .or_else(|| match_event_type(event))?;
+
+    let (base_mint, quote_mint) = match mints.as_slice() {
+        [mint] => (mint.clone(), None),
+        [first, second] if is_quote_mint(first) && !is_quote_mint(second) => {
+            (second.clone(), Some(first.clone()))
+        }
+        [first, second] => (first.clone(), Some(second.clone())),
+        _ => return None,
+    };
+
+    Some(Launch {
+        kind,
+        venue,
+        base_tokens: deposited(event, &base_mint),
+        quote_tokens: quote_mint
+            .as_deref()
+            .and_then(|mint| deposited(event, mint)),
+        base_mint,
+        quote_mint,
+        pool,
+    })
+}
+
+type Match = (LaunchKind, String, Option<String>, Vec<String>);
+
+fn match_instruction(instruction: &Instruction) -> Option<Match> {
+    let known = LAUNCH_INSTRUCTIONS
+        .iter()
+        .find(|known| known.program_id == instruction.program_id)?;
+    if !instruction.data_bytes()?.starts_with(known.discriminator) {
+        return None;
+    }
+    let mints = known
+        .mints
+        .iter()
+        .map(|index| instruction.accounts.get(*index).cloned())
+        .collect::<Option<Vec<_>>>()?;
+    Some((
+        known.kind,
+        known.venue.to_string(),
+        instruction.accounts.get(known.pool).cloned(),
+        mints,
+    ))
+}
+
+/// Helius labels pool creation `CREATE_POOL` and pump.fun launches `CREATE`; the
+/// mints then come from the token transfers, quote mint last.
+fn match_event_type(event: &HeliusWebhook) -> Option<Match> {
+    let event_type = event.event_type.as_deref()?.to_ascii_uppercase();
+    let source = event
+        .source
+        .clone()
+        .unwrap_or_else(|| "UNKNOWN".to_string());
+    let kind = match event_type.as_str() {
+        "CREATE_POOL" => LaunchKind::PoolCreated,
+        "CREATE" | "TOKEN_MINT" if source.eq_ignore_ascii_case("PUMP_FUN") => {
+            LaunchKind::TokenCreated
+        }
+        _ => return None,
+    };
+
+    let mut mints: Vec<String> = Vec::new();
+    for transfer in &event.events.token_transfers {
+        if !mints.contains(&transfer.mint) {
+            mints.push(transfer.mint.clone());
+        }
+    }
+    mints.sort_by_key(|mint| is_quote_mint(mint));
+    let wanted = match kind {
+        LaunchKind::PoolCreated => 2,
+        LaunchKind::TokenCreated => 1,
+    };
+    mints.truncate(wanted);
+    (mints.len() == wanted).then_some((kind, source, None, mints))
+}
+
+fn is_quote_mint(mint: &str) -> bool {
+    [WSOL_MINT, USDC_MINT, USDT_MINT].contains(&mint)
+}
+
+/// Tokens of `mint` the fee payer sent out in the transaction.
+fn deposited(event: &HeliusWebhook, mint: &str) -> Option<f64> {
+    let creator = event.fee_payer.as_deref()?;
+    let total: f64 = event
+        .events
+        .token_transfers
+        .iter()
+        .filter(|transfer| {
+            transfer.mint == mint && transfer.from_user_account.as_deref() == Some(creator)
+        })
+        .filter_map(|transfer| transfer.token_amount)
+        .sum();
+    (total > 0.0).then_some(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LaunchKind, WSOL_MINT, detect};
+    use crate::helius::HeliusWebhook;
+
+    fn event(value: serde_json::Value) -> HeliusWebhook {
+        serde_json::from_value(value).expect("webhook")
+    }
+
+    #[test]
+    fn detects_raydium_pool_from_inner_instruction() {
+        let mut accounts: Vec<String> = (0..10).map(|i| format!("acct{i}")).collect();
+        accounts[4] = "Pool111".to_string();
+        accounts[8] = WSOL_MINT.to_string();
+        accounts[9] = "NewMint".to_string();
+        let launch = detect(&event(serde_json::json!({
+            "signature": "sig",
+            "type": "UNKNOWN",
+            "feePayer": "Dev111",
+            "events": {"tokenTransfers": [
+                {"mint": WSOL_MINT, "fromUserAccount": "Dev111", "toUserAccount": "Vault1", "tokenAmount": 20.0},
+                {"mint": "NewMint", "fromUserAccount": "Dev111", "toUserAccount": "Vault2", "tokenAmount": 800000000.0}
+            ]},
+            "instructions": [{
+                "programId": "Router111",
+                "data": "",
+                "innerInstructions": [{
+                    "programId": "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8",
+                    "accounts": accounts,
+                    "data": "LKx4sgLPEF"
+                }]
+            }]
+        })))
+        .expect("launch");
+
+        assert_eq!(LaunchKind::PoolCreated, launch.kind);
+        assert_eq!("Raydium AMM v4", launch.venue);
+        assert_eq!("NewMint", launch.base_mint);
+        assert_eq!(Some(WSOL_MINT), launch.quote_mint.as_deref());
+        assert_eq!(Some("Pool111"), launch.pool.as_deref());
+        assert_eq!(Some(20.0), launch.quote_tokens);
+        assert_eq!(Some(8_000.0), launch.initial_liquidity_usd(200.0));
+        assert_eq!(Some(0.000005), launch.initial_price_usd(200.0));
+    }
+
+    #[test]
+    fn detects_pump_fun_create_and_ignores_other_instructions() {
+        let create = event(serde_json::json!({
+            "signature": "sig",
+            "instructions": [{
+                "programId": "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P",
+                "accounts": ["PumpMint", "Authority", "Curve111"],
+                "data": "6yuon6s3h9egrpJ"
+            }]
+        }));
+        let launch = detect(&create).expect("launch");
+        assert_eq!(LaunchKind::TokenCreated, launch.kind);
+        assert_eq!("PumpMint", launch.base_mint);
+        assert_eq!(Some("Curve111"), launch.pool.as_deref());
+        assert_eq!(None, launch.quote_mint);
+
+        // Same program, different instruction (a buy).
+        let buy = event(serde_json::json!({
+            "signature": "sig",
+            "type": "SWAP",
+            "instructions": [{
+                "programId": "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P",
+                "accounts": ["Global", "Fee", "PumpMint"],
+                "data": "NhGqtrJt1qPGDPpxYD3hHH"
+            }]
+        }));
+        assert_eq!(None, detect(&buy));
+    }
+
+    #[test]
+    fn falls_back_to_helius_type() {
+        let launch = detect(&event(serde_json::json!({
+            "signature": "sig",
+            "type": "CREATE_POOL",
+            "source": "METEORA",
+            "events": {"tokenTransfers": [
+                {"mint": WSOL_MINT, "tokenAmount": 5.0},
+                {"mint": "MeteoraMint", "tokenAmount": 1000.0}
+            ]}
+        })))
+        .expect("launch");
+
+        assert_eq!("METEORA", launch.venue);
+        assert_eq!("MeteoraMint", launch.base_mint);
+        assert_eq!(Some(WSOL_MINT), launch.quote_mint.as_deref());
+        assert_eq!(None, launch.pool);
+    }
+}
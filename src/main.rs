@@ -1,56 +1,155 @@
+mod age;
 mod analyzer;
+mod bot;
+mod cli;
 mod config;
+mod cooldown;
+mod dedup;
+mod filter;
+mod growth;
+mod health;
 mod helius;
+mod latency;
+mod launch;
+mod metrics;
 mod moralis;
+mod performance;
+mod profiles;
+mod pumpfun;
+mod reload;
+mod risk;
+mod rpc;
+mod server;
 mod telegram;
+mod updates;
+mod volume;
+mod watch;
+mod watchlist;
 
 use analyzer::Analyzer;
-use axum::{Json, Router, extract::State, http::StatusCode, routing::post};
+use bot::Bot;
+use cli::CliArgs;
 use config::AppConfig;
-use helius::HeliusWebhook;
-use std::{net::SocketAddr, sync::Arc};
+use health::Readiness;
+use metrics::Metrics;
+use performance::PerformanceTracker;
+use server::AppState;
+use std::{sync::Arc, time::Duration};
+use telegram::TelegramClient;
 use tracing::{error, info};
+use watchlist::{MintLists, Watchlist};
 
-#[derive(Clone)]
-struct AppState {
-    analyzer: Analyzer,
-}
-
-async fn webhook_handler(
-    State(state): State<Arc<AppState>>,
-    Json(payload): Json<HeliusWebhook>,
-) -> StatusCode {
-    let analyzer = state.analyzer.clone();
-    tokio::spawn(async move {
-        if let Err(err) = analyzer.process_event(payload).await {
-            error!(error = ?err, "analyzer_error");
-        }
-    });
-
-    StatusCode::ACCEPTED
-}
+/// How often Telegram and Moralis are probed for `/readyz`.
+const READINESS_PROBE_INTERVAL: Duration = Duration::from_secs(30);
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = match CliArgs::from_env() {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{err}\n{}", cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    if args.help {
+        println!("{}", cli::USAGE);
+        return Ok(());
+    }
+
     tracing_subscriber::fmt()
         .with_target(false)
         .json()
         .with_level(true)
         .init();
 
-    let config = AppConfig::from_env()?;
-    let analyzer = Analyzer::new(&config);
-    let app_state = Arc::new(AppState { analyzer });
+    let config = match AppConfig::load(args.config_path.as_deref()) {
+        Ok(config) => config,
+        Err(err) => {
+            error!(error = %err, "config_error");
+            std::process::exit(1);
+        }
+    };
+
+    if args.print_config {
+        print!("{}", config.to_redacted_toml());
+        return Ok(());
+    }
 
-    let app = Router::new()
-        .route("/webhook", post(webhook_handler))
-        .with_state(app_state);
+    if args.healthcheck {
+        if let Err(err) = server::healthcheck(&config.server).await {
+            error!(error = %err, "healthcheck_failed");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let readiness = Readiness::new(config.max_in_flight_events);
+    let metrics = Metrics::new();
+    let lists = Watchlist::load(
+        &config.watchlist.wallets,
+        config.watchlist.store_path.clone(),
+    )
+    .and_then(|watchlist| Ok((watchlist, MintLists::load(&config.mints)?)));
+    let (watchlist, mints) = match lists {
+        Ok(lists) => lists,
+        Err(err) => {
+            error!(error = %err, "watchlist_error");
+            std::process::exit(1);
+        }
+    };
+    let performance = PerformanceTracker::default();
+    let analyzer = Analyzer::new(
+        &config,
+        watchlist.clone(),
+        mints.clone(),
+        performance.clone(),
+        readiness.clone(),
+        metrics.clone(),
+    );
+    let app_state = Arc::new(AppState {
+        analyzer: analyzer.clone(),
+        readiness: readiness.clone(),
+        metrics: metrics.clone(),
+        performance: performance.clone(),
+        admin_token: config.admin_token.clone(),
+    });
 
-    let addr = SocketAddr::from(([0, 0, 0, 0], 3000));
-    info!("listening on http://{addr}/webhook");
+    if let Some(path) = args.config_path.clone() {
+        tokio::spawn(reload::watch_config(path, config.clone(), analyzer.clone()));
+    }
+
+    if config.telegram_commands {
+        let telegram = TelegramClient::new(
+            config.telegram_bot_token.clone(),
+            config.telegram_api_base.clone(),
+        );
+        let bot = Bot::new(
+            telegram,
+            config.telegram_chat_id.clone(),
+            watchlist,
+            mints,
+            performance,
+            metrics.clone(),
+        );
+        tokio::spawn(bot.run());
+    }
+
+    // Probing never stops, so `/readyz` also notices a dependency going away later.
+    tokio::spawn(async move {
+        let mut was_ready = false;
+        loop {
+            analyzer.probe_dependencies().await;
+            let ready = readiness.telegram_ok() && readiness.price_provider_ok();
+            if ready && !was_ready {
+                info!("dependencies_ready");
+            }
+            was_ready = ready;
+            tokio::time::sleep(READINESS_PROBE_INTERVAL).await;
+        }
+    });
 
-    let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    let app = server::router(app_state);
+    server::serve(app, &config.server).await?;
 
     Ok(())
 }
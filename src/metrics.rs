@@ -0,0 +1,248 @@
+use axum::{
+    extract::State,
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, Opts, Registry,
+    TextEncoder,
+};
+use std::{sync::Arc, time::Duration};
+use tracing::error;
+
+use crate::server::AppState;
+
+/// Buckets for outbound HTTP calls, in seconds.
+const REQUEST_BUCKETS: &[f64] = &[0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Buckets for block-time lag; block time only has one-second resolution.
+const CHAIN_LAG_BUCKETS: &[f64] = &[1.0, 2.0, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0];
+
+/// Prometheus collectors shared by the HTTP layer and the analyzer.
+#[derive(Clone)]
+pub struct Metrics {
+    registry: Registry,
+    webhooks_received: IntCounter,
+    events_skipped: IntCounterVec,
+    filter_decisions: IntCounterVec,
+    alerts_sent: IntCounter,
+    alerts_failed: IntCounter,
+    provider_errors: IntCounterVec,
+    provider_latency: HistogramVec,
+    webhook_to_alert: Histogram,
+    chain_to_intake: Histogram,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let webhooks_received = IntCounter::new(
+            "solana_monitor_webhooks_received_total",
+            "Helius webhook deliveries accepted by /webhook",
+        )
+        .expect("valid metric");
+        let events_skipped = IntCounterVec::new(
+            Opts::new(
+                "solana_monitor_events_skipped_total",
+                "Events dropped before filtering, by reason",
+            ),
+            &["reason"],
+        )
+        .expect("valid metric");
+        let filter_decisions = IntCounterVec::new(
+            Opts::new(
+                "solana_monitor_filter_decisions_total",
+                "Filter verdicts, by filter, decision and reason",
+            ),
+            &["filter", "decision", "reason"],
+        )
+        .expect("valid metric");
+        let alerts_sent = IntCounter::new(
+            "solana_monitor_alerts_sent_total",
+            "Telegram alerts delivered",
+        )
+        .expect("valid metric");
+        let alerts_failed = IntCounter::new(
+            "solana_monitor_alerts_failed_total",
+            "Telegram alerts that failed to deliver",
+        )
+        .expect("valid metric");
+        let provider_errors = IntCounterVec::new(
+            Opts::new(
+                "solana_monitor_provider_errors_total",
+                "External API errors, by source and HTTP status",
+            ),
+            &["source", "status"],
+        )
+        .expect("valid metric");
+        let provider_latency = HistogramVec::new(
+            HistogramOpts::new(
+                "solana_monitor_provider_request_duration_seconds",
+                "Latency of external API calls, by source",
+            )
+            .buckets(REQUEST_BUCKETS.to_vec()),
+            &["source"],
+        )
+        .expect("valid metric");
+        let webhook_to_alert = Histogram::with_opts(
+            HistogramOpts::new(
+                "solana_monitor_webhook_to_alert_seconds",
+                "Time from webhook receipt to Telegram delivery",
+            )
+            .buckets(REQUEST_BUCKETS.to_vec()),
+        )
+        .expect("valid metric");
+
+        let chain_to_intake = Histogram::with_opts(
+            HistogramOpts::new(
+                "solana_monitor_chain_to_intake_seconds",
+                "Time from block time to webhook receipt",
+            )
+            .buckets(CHAIN_LAG_BUCKETS.to_vec()),
+        )
+        .expect("valid metric");
+
+        registry
+            .register(Box::new(webhooks_received.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(events_skipped.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(filter_decisions.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(alerts_sent.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(alerts_failed.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(provider_errors.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(provider_latency.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(webhook_to_alert.clone()))
+            .expect("unique metric");
+        registry
+            .register(Box::new(chain_to_intake.clone()))
+            .expect("unique metric");
+
+        Self {
+            registry,
+            webhooks_received,
+            events_skipped,
+            filter_decisions,
+            alerts_sent,
+            alerts_failed,
+            provider_errors,
+            provider_latency,
+            webhook_to_alert,
+            chain_to_intake,
+        }
+    }
+
+    pub fn webhook_received(&self) {
+        self.webhooks_received.inc();
+    }
+
+    pub fn event_skipped(&self, reason: &str) {
+        self.events_skipped.with_label_values(&[reason]).inc();
+    }
+
+    pub fn filter_decision(&self, filter: &str, decision: &str, reason: &str) {
+        self.filter_decisions
+            .with_label_values(&[filter, decision, reason])
+            .inc();
+    }
+
+    pub fn alert_sent(&self) {
+        self.alerts_sent.inc();
+    }
+
+    pub fn alert_failed(&self) {
+        self.alerts_failed.inc();
+    }
+
+    pub fn provider_error(&self, source: &str, status: &str) {
+        self.provider_errors
+            .with_label_values(&[source, status])
+            .inc();
+    }
+
+    pub fn observe_provider_latency(&self, source: &str, elapsed: Duration) {
+        self.provider_latency
+            .with_label_values(&[source])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn observe_webhook_to_alert(&self, elapsed: Duration) {
+        self.webhook_to_alert.observe(elapsed.as_secs_f64());
+    }
+
+    pub fn observe_chain_to_intake(&self, elapsed: Duration) {
+        self.chain_to_intake.observe(elapsed.as_secs_f64());
+    }
+
+    /// Renders every registered collector in the Prometheus text exposition format.
+    pub fn render(&self) -> Result<String, prometheus::Error> {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+        Ok(String::from_utf8_lossy(&buffer).into_owned())
+    }
+}
+
+pub async fn metrics_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    match state.metrics.render() {
+        Ok(body) => (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, prometheus::TEXT_FORMAT)],
+            body,
+        ),
+        Err(err) => {
+            error!(error = ?err, "metrics_render_failed");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                [(header::CONTENT_TYPE, "text/plain")],
+                String::new(),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Metrics;
+    use std::time::Duration;
+
+    #[test]
+    fn renders_labelled_counters_and_histograms() {
+        let metrics = Metrics::new();
+        metrics.webhook_received();
+        metrics.event_skipped("duplicate_signature");
+        metrics.filter_decision("market_cap", "fail", "out_of_cap_range");
+        metrics.provider_error("moralis", "503");
+        metrics.observe_provider_latency("telegram", Duration::from_millis(120));
+
+        let body = metrics.render().expect("render");
+
+        assert!(body.contains("solana_monitor_webhooks_received_total 1"));
+        assert!(
+            body.contains(r#"solana_monitor_events_skipped_total{reason="duplicate_signature"} 1"#)
+        );
+        assert!(body.contains(
+            r#"solana_monitor_filter_decisions_total{decision="fail",filter="market_cap",reason="out_of_cap_range"} 1"#
+        ));
+        assert!(
+            body.contains(
+                r#"solana_monitor_provider_errors_total{source="moralis",status="503"} 1"#
+            )
+        );
+        assert!(body.contains(
+            r#"solana_monitor_provider_request_duration_seconds_count{source="telegram"} 1"#
+        ));
+    }
+}
@@ -1,7 +1,11 @@
 use reqwest::Client;
 use serde::Deserialize;
+use std::time::{SystemTime, UNIX_EPOCH};
 use thiserror::Error;
 
+/// Wrapped SOL mint; always priced by Moralis, so it makes a cheap reachability probe.
+const PROBE_MINT: &str = "So11111111111111111111111111111111111111112";
+
 #[derive(Clone)]
 pub struct MoralisClient {
     http: Client,
@@ -25,6 +29,50 @@ impl MoralisClient {
 
     pub async fn fetch_token_metrics(&self, mint: &str) -> Result<TokenMetrics, MoralisError> {
         let url = format!("{}/tokens/{mint}/price?chain=solana", self.base_url);
+        let payload: MoralisPriceResponse = self.get_json(url).await?;
+        Ok(TokenMetrics {
+            price_usd: payload.usd_price,
+            market_cap_usd: payload.market_cap_usd,
+            ..TokenMetrics::default()
+        })
+    }
+
+    /// Liquidity and 24h volume of the token's most liquid trading pair.
+    pub async fn fetch_pair_stats(&self, mint: &str) -> Result<PairStats, MoralisError> {
+        let url = format!("{}/tokens/{mint}/pairs?chain=solana", self.base_url);
+        let payload: MoralisPairsResponse = self.get_json(url).await?;
+
+        let best = payload.pairs.into_iter().max_by(|a, b| {
+            a.liquidity_usd
+                .unwrap_or(0.0)
+                .total_cmp(&b.liquidity_usd.unwrap_or(0.0))
+        });
+        Ok(best
+            .map(|pair| PairStats {
+                pair_address: pair.pair_address,
+                liquidity_usd: pair.liquidity_usd,
+                volume_24h_usd: pair.volume_24h_usd,
+            })
+            .unwrap_or_default())
+    }
+
+    pub async fn fetch_holder_count(&self, mint: &str) -> Result<Option<u64>, MoralisError> {
+        let url = format!("{}/tokens/{mint}/holders?chain=solana", self.base_url);
+        let payload: MoralisHoldersResponse = self.get_json(url).await?;
+        Ok(payload.total_holders)
+    }
+
+    /// Unix time the token was created, when Moralis knows it.
+    pub async fn fetch_creation_time(&self, mint: &str) -> Result<Option<i64>, MoralisError> {
+        let url = format!("{}/tokens/{mint}/metadata?chain=solana", self.base_url);
+        let payload: MoralisMetadataResponse = self.get_json(url).await?;
+        Ok(payload.created_at.and_then(Timestamp::unix_secs))
+    }
+
+    async fn get_json<T: serde::de::DeserializeOwned>(
+        &self,
+        url: String,
+    ) -> Result<T, MoralisError> {
         let response = self
             .http
             .get(url)
@@ -36,18 +84,50 @@ impl MoralisClient {
             return Err(MoralisError::HttpStatus(response.status()));
         }
 
-        let payload: MoralisPriceResponse = response.json().await?;
-        Ok(TokenMetrics {
-            price_usd: payload.usd_price,
-            market_cap_usd: payload.market_cap_usd,
-        })
+        Ok(response.json().await?)
+    }
+
+    /// Confirms the API answers for a well-known mint with the configured key.
+    pub async fn probe(&self) -> Result<(), MoralisError> {
+        self.fetch_token_metrics(PROBE_MINT).await.map(|_| ())
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct TokenMetrics {
     pub price_usd: Option<f64>,
     pub market_cap_usd: Option<f64>,
+    pub liquidity_usd: Option<f64>,
+    pub volume_24h_usd: Option<f64>,
+    pub holders: Option<u64>,
+    pub pair_address: Option<String>,
+    /// Unix time the token was created.
+    pub created_at: Option<i64>,
+}
+
+impl TokenMetrics {
+    /// Seconds since creation; clock skew never makes a token younger than zero.
+    pub fn age_secs(&self) -> Option<f64> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs() as i64;
+        self.created_at
+            .map(|created_at| now.saturating_sub(created_at).max(0) as f64)
+    }
+
+    pub fn apply_pair_stats(&mut self, stats: PairStats) {
+        self.liquidity_usd = stats.liquidity_usd;
+        self.volume_24h_usd = stats.volume_24h_usd;
+        self.pair_address = stats.pair_address;
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PairStats {
+    pub pair_address: Option<String>,
+    pub liquidity_usd: Option<f64>,
+    pub volume_24h_usd: Option<f64>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -58,6 +138,93 @@ struct MoralisPriceResponse {
     market_cap_usd: Option<f64>,
 }
 
+#[derive(Debug, Deserialize)]
+struct MoralisPairsResponse {
+    #[serde(default)]
+    pairs: Vec<MoralisPair>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MoralisPair {
+    #[serde(default, alias = "pairAddress")]
+    pair_address: Option<String>,
+    #[serde(default, alias = "liquidityUsd")]
+    liquidity_usd: Option<f64>,
+    #[serde(default, alias = "volume24hrUsd", alias = "volume_24hr_usd")]
+    volume_24h_usd: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MoralisHoldersResponse {
+    #[serde(default, alias = "totalHolders")]
+    total_holders: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MoralisMetadataResponse {
+    #[serde(
+        default,
+        alias = "createdAt",
+        alias = "created_at",
+        alias = "blockTimestamp"
+    )]
+    created_at: Option<Timestamp>,
+}
+
+/// Moralis reports timestamps either as Unix seconds or as RFC 3339 strings.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Timestamp {
+    Secs(i64),
+    Text(String),
+}
+
+impl Timestamp {
+    fn unix_secs(self) -> Option<i64> {
+        match self {
+            Timestamp::Secs(secs) => Some(secs),
+            Timestamp::Text(text) => text.parse().ok().or_else(|| parse_rfc3339(&text)),
+        }
+    }
+}
+
+/// Parses `YYYY-MM-DDTHH:MM:SS[.frac](Z|±HH:MM)` into Unix seconds.
+fn parse_rfc3339(text: &str) -> Option<i64> {
+    let number = |range: std::ops::Range<usize>| text.get(range)?.parse::<i64>().ok();
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if text.get(4..5)? != "-" || !matches!(text.get(10..11)?, "T" | "t" | " ") {
+        return None;
+    }
+
+    let rest = text.get(19..)?;
+    let zone = rest.trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+    let offset = match zone {
+        "Z" | "z" => 0,
+        _ => {
+            let sign = match zone.get(0..1)? {
+                "+" => 1,
+                "-" => -1,
+                _ => return None,
+            };
+            let hours = zone.get(1..3)?.parse::<i64>().ok()?;
+            let minutes = zone.get(4..6)?.parse::<i64>().ok()?;
+            sign * (hours * 3600 + minutes * 60)
+        }
+    };
+
+    // Days from 1970-01-01 to the civil date (Howard Hinnant's algorithm).
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    Some(days * 86_400 + hour * 3600 + minute * 60 + second - offset)
+}
+
 #[derive(Debug, Error)]
 pub enum MoralisError {
     #[error("moralis request failed: {0}")]
@@ -65,3 +232,38 @@ pub enum MoralisError {
     #[error("moralis returned non-success status {0}")]
     HttpStatus(reqwest::StatusCode),
 }
+
+impl MoralisError {
+    /// Short label for metrics: the HTTP status code, or `transport` for network failures.
+    pub fn status_label(&self) -> String {
+        match self {
+            MoralisError::Transport(_) => "transport".to_string(),
+            MoralisError::HttpStatus(status) => status.as_u16().to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Timestamp, parse_rfc3339};
+
+    #[test]
+    fn parses_rfc3339_timestamps() {
+        assert_eq!(Some(0), parse_rfc3339("1970-01-01T00:00:00Z"));
+        assert_eq!(
+            Some(1_704_105_000),
+            parse_rfc3339("2024-01-01T10:30:00.000Z")
+        );
+        assert_eq!(
+            Some(1_704_105_000),
+            parse_rfc3339("2024-01-01T12:30:00+02:00")
+        );
+        assert_eq!(None, parse_rfc3339("yesterday"));
+    }
+
+    #[test]
+    fn accepts_numeric_and_text_timestamps() {
+        assert_eq!(Some(42), Timestamp::Secs(42).unix_secs());
+        assert_eq!(Some(42), Timestamp::Text("42".to_string()).unix_secs());
+    }
+}
@@ -0,0 +1,427 @@
+use axum::{Json, extract::State};
+use serde::Serialize;
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tokio::sync::Semaphore;
+use tracing::{info, warn};
+
+use crate::{
+    config::PerformanceSettings, metrics::Metrics, moralis::MoralisClient, server::AppState,
+};
+
+/// Alerts followed up at the same time; each one holds a task until its last offset.
+const MAX_PENDING_FOLLOW_UPS: usize = 1_000;
+
+/// Follow-up results per rule, kept in memory until restart.
+#[derive(Clone)]
+pub struct PerformanceTracker {
+    inner: Arc<Mutex<BTreeMap<String, RuleStats>>>,
+    pending: Arc<Semaphore>,
+}
+
+impl Default for PerformanceTracker {
+    fn default() -> Self {
+        Self::with_capacity(MAX_PENDING_FOLLOW_UPS)
+    }
+}
+
+#[derive(Default)]
+struct RuleStats {
+    alerts: u64,
+    by_offset: BTreeMap<Duration, OffsetStats>,
+}
+
+#[derive(Default)]
+struct OffsetStats {
+    checks: u64,
+    hits: u64,
+    total_return_percent: f64,
+}
+
+/// A sent alert whose price is checked again later.
+pub struct FollowUp {
+    pub rule: String,
+    pub mint: String,
+    pub signature: String,
+    pub price_usd: f64,
+    pub sent_at: Instant,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StatsBody {
+    pub rules: Vec<RuleReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RuleReport {
+    pub rule: String,
+    /// Alerts sent with a price, and so followed up.
+    pub alerts: u64,
+    pub offsets: Vec<OffsetReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OffsetReport {
+    pub offset_secs: u64,
+    pub checks: u64,
+    pub hits: u64,
+    pub hit_rate_percent: f64,
+    pub average_return_percent: f64,
+}
+
+impl PerformanceTracker {
+    fn with_capacity(pending: usize) -> Self {
+        Self {
+            inner: Arc::default(),
+            pending: Arc::new(Semaphore::new(pending)),
+        }
+    }
+
+    /// Follows `alert` up in the background; returns `false`, without tracking it,
+    /// when `MAX_PENDING_FOLLOW_UPS` alerts are already pending.
+    pub fn spawn(
+        &self,
+        moralis: MoralisClient,
+        metrics: Metrics,
+        alert: FollowUp,
+        settings: PerformanceSettings,
+    ) -> bool {
+        let Ok(permit) = self.pending.clone().try_acquire_owned() else {
+            return false;
+        };
+        let tracker = self.clone();
+        tokio::spawn(async move {
+            let _permit = permit;
+            tracker.follow_up(moralis, metrics, alert, settings).await;
+        });
+        true
+    }
+
+    /// Checks the price of `alert.mint` at every offset and records the return.
+    ///
+    /// Offsets are measured from when the alert was sent; a failed price lookup
+    /// skips that offset only.
+    async fn follow_up(
+        &self,
+        moralis: MoralisClient,
+        metrics: Metrics,
+        alert: FollowUp,
+        settings: PerformanceSettings,
+    ) {
+        self.inner
+            .lock()
+            .expect("performance stats poisoned")
+            .entry(alert.rule.clone())
+            .or_default()
+            .alerts += 1;
+
+        for offset in settings.offsets {
+            tokio::time::sleep_until((alert.sent_at + offset).into()).await;
+            let started = Instant::now();
+            let fetched = moralis.fetch_token_metrics(&alert.mint).await;
+            metrics.observe_provider_latency("moralis", started.elapsed());
+            let price = match fetched.map(|token| token.price_usd) {
+                Ok(Some(price)) => price,
+                Ok(None) => {
+                    warn!(
+                        signature = alert.signature.as_str(),
+                        mint = alert.mint.as_str(),
+                        rule = alert.rule.as_str(),
+                        offset_secs = offset.as_secs(),
+                        "follow_up_missing_price"
+                    );
+                    continue;
+                }
+                Err(err) => {
+                    metrics.provider_error("moralis", &err.status_label());
+                    warn!(
+                        signature = alert.signature.as_str(),
+                        mint = alert.mint.as_str(),
+                        error = ?err,
+                        source = "moralis",
+                        lookup = "follow_up",
+                        "external_api_error"
+                    );
+                    continue;
+                }
+            };
+
+            let return_percent = (price / alert.price_usd - 1.0) * 100.0;
+            let hit = return_percent >= settings.hit_percent;
+            self.record(&alert.rule, offset, return_percent, hit);
+            info!(
+                signature = alert.signature.as_str(),
+                mint = alert.mint.as_str(),
+                rule = alert.rule.as_str(),
+                offset_secs = offset.as_secs(),
+                alert_price_usd = alert.price_usd,
+                price_usd = price,
+                return_percent = return_percent,
+                hit = hit,
+                "alert_follow_up"
+            );
+        }
+    }
+
+    pub(crate) fn record(&self, rule: &str, offset: Duration, return_percent: f64, hit: bool) {
+        let mut inner = self.inner.lock().expect("performance stats poisoned");
+        let stats = inner
+            .entry(rule.to_string())
+            .or_default()
+            .by_offset
+            .entry(offset)
+            .or_default();
+        stats.checks += 1;
+        stats.hits += u64::from(hit);
+        stats.total_return_percent += return_percent;
+    }
+
+    /// Per-rule results in rule name order, offsets ascending.
+    pub fn report(&self) -> Vec<RuleReport> {
+        let inner = self.inner.lock().expect("performance stats poisoned");
+        inner
+            .iter()
+            .map(|(rule, stats)| RuleReport {
+                rule: rule.clone(),
+                alerts: stats.alerts,
+                offsets: stats
+                    .by_offset
+                    .iter()
+                    .map(|(offset, stats)| OffsetReport {
+                        offset_secs: offset.as_secs(),
+                        checks: stats.checks,
+                        hits: stats.hits,
+                        hit_rate_percent: stats.hits as f64 / stats.checks as f64 * 100.0,
+                        average_return_percent: stats.total_return_percent / stats.checks as f64,
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// The report as a chat message.
+    pub fn describe(&self) -> String {
+        let rules = self.report();
+        if rules.is_empty() {
+            return "No alerts followed up yet".to_string();
+        }
+        let blocks: Vec<String> = rules
+            .iter()
+            .map(|rule| {
+                let mut block = format!("Rule {} ({} alerts):", rule.rule, rule.alerts);
+                if rule.offsets.is_empty() {
+                    block.push_str("\nno checks yet");
+                }
+                for offset in &rule.offsets {
+                    block.push_str(&format!(
+                        "\n+{}: {:.0}% hits ({}/{}), avg {:+.1}%",
+                        format_offset(offset.offset_secs),
+                        offset.hit_rate_percent,
+                        offset.hits,
+                        offset.checks,
+                        offset.average_return_percent
+                    ));
+                }
+                block
+            })
+            .collect();
+        blocks.join("\n\n")
+    }
+}
+
+/// `90` → `90s`, `300` → `5m`, `3600` → `1h`.
+fn format_offset(secs: u64) -> String {
+    match secs {
+        secs if secs % 3_600 == 0 => format!("{}h", secs / 3_600),
+        secs if secs % 60 == 0 => format!("{}m", secs / 60),
+        secs => format!("{secs}s"),
+    }
+}
+
+pub async fn stats_handler(State(state): State<Arc<AppState>>) -> Json<StatsBody> {
+    Json(StatsBody {
+        rules: state.performance.report(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FollowUp, PerformanceTracker, stats_handler};
+    use crate::{
+        analyzer::Analyzer,
+        config::{AppConfig, PerformanceSettings},
+        health::Readiness,
+        metrics::Metrics,
+        moralis::MoralisClient,
+        server::AppState,
+        watchlist::{MintLists, Watchlist},
+    };
+    use axum::{Json, Router, extract::State, http::StatusCode, routing::get};
+    use serde_json::{Value, json};
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::{Duration, Instant},
+    };
+
+    /// Serves Moralis `/price` answers from `responses`, one per request in order.
+    async fn mock_moralis(responses: Vec<(StatusCode, Value)>) -> String {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = Router::new().route(
+            "/tokens/:mint/price",
+            get(move || async move {
+                let call = calls.fetch_add(1, Ordering::SeqCst);
+                let (status, body) = responses[call].clone();
+                (status, Json(body))
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock moralis");
+        let addr = listener.local_addr().expect("mock moralis addr");
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        format!("http://{addr}")
+    }
+
+    fn follow_up(sent_at: Instant) -> FollowUp {
+        FollowUp {
+            rule: "micro".to_string(),
+            mint: "Mint111".to_string(),
+            signature: "sig".to_string(),
+            price_usd: 1.0,
+            sent_at,
+        }
+    }
+
+    fn settings(offsets: &[u64]) -> PerformanceSettings {
+        PerformanceSettings {
+            offsets: offsets.iter().copied().map(Duration::from_secs).collect(),
+            hit_percent: 20.0,
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn follow_up_checks_each_offset_and_skips_failed_lookups() {
+        let url = mock_moralis(vec![
+            (StatusCode::OK, json!({"usdPrice": 1.5})),
+            (StatusCode::SERVICE_UNAVAILABLE, json!({})),
+            (StatusCode::OK, json!({"usdPrice": 0.5})),
+        ])
+        .await;
+        let tracker = PerformanceTracker::default();
+        let sent_at = Instant::now();
+        let paused_clock = tokio::time::Instant::now();
+
+        tracker
+            .follow_up(
+                MoralisClient::new("key".to_string(), url),
+                Metrics::new(),
+                follow_up(sent_at),
+                settings(&[60, 300, 3_600]),
+            )
+            .await;
+
+        assert!(paused_clock.elapsed() >= Duration::from_secs(3_600));
+        let report = tracker.report();
+        assert_eq!(1, report[0].alerts);
+        let checks: Vec<(u64, u64, u64, f64)> = report[0]
+            .offsets
+            .iter()
+            .map(|offset| {
+                (
+                    offset.offset_secs,
+                    offset.checks,
+                    offset.hits,
+                    offset.average_return_percent,
+                )
+            })
+            .collect();
+        assert_eq!(vec![(60, 1, 1, 50.0), (3_600, 1, 0, -50.0)], checks);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn drops_follow_ups_beyond_the_pending_limit() {
+        let tracker = PerformanceTracker::with_capacity(1);
+        let moralis = MoralisClient::new("key".to_string(), "http://127.0.0.1:9".to_string());
+        let start = || {
+            tracker.spawn(
+                moralis.clone(),
+                Metrics::new(),
+                follow_up(Instant::now()),
+                settings(&[3_600]),
+            )
+        };
+
+        assert!(start());
+        assert!(!start());
+    }
+
+    #[tokio::test]
+    async fn stats_handler_reports_per_rule_offsets() {
+        let config = AppConfig::from_toml(
+            "[moralis]\napi_key = \"key\"\n\n[telegram]\nbot_token = \"token\"\nchat_id = \"42\"\n",
+        )
+        .expect("config");
+        let performance = PerformanceTracker::default();
+        performance.record("micro", Duration::from_secs(300), 40.0, true);
+        let readiness = Readiness::new(1);
+        let state = AppState {
+            analyzer: Analyzer::new(
+                &config,
+                Watchlist::load(&[], None).expect("watchlist"),
+                MintLists::load(&config.mints).expect("mint lists"),
+                performance.clone(),
+                readiness.clone(),
+                Metrics::new(),
+            ),
+            readiness,
+            metrics: Metrics::new(),
+            performance,
+            admin_token: None,
+        };
+
+        let Json(body) = stats_handler(State(Arc::new(state))).await;
+        assert_eq!(
+            json!({"rules": [{
+                "rule": "micro",
+                "alerts": 0,
+                "offsets": [{
+                    "offset_secs": 300,
+                    "checks": 1,
+                    "hits": 1,
+                    "hit_rate_percent": 100.0,
+                    "average_return_percent": 40.0
+                }]
+            }]}),
+            serde_json::to_value(body).expect("serialize")
+        );
+    }
+
+    #[test]
+    fn aggregates_hit_rate_and_average_return_per_offset() {
+        let tracker = PerformanceTracker::default();
+        let minute = Duration::from_secs(60);
+        tracker.record("micro", minute, 30.0, true);
+        tracker.record("micro", minute, -10.0, false);
+        tracker.record("micro", Duration::from_secs(3_600), 120.0, true);
+
+        let report = tracker.report();
+        assert_eq!(1, report.len());
+        let offsets = &report[0].offsets;
+        assert_eq!(
+            (60, 2, 1),
+            (offsets[0].offset_secs, offsets[0].checks, offsets[0].hits)
+        );
+        assert_eq!(50.0, offsets[0].hit_rate_percent);
+        assert_eq!(10.0, offsets[0].average_return_percent);
+        assert_eq!(
+            "Rule micro (0 alerts):\n+1m: 50% hits (1/2), avg +10.0%\n+1h: 100% hits (1/1), avg +120.0%",
+            tracker.describe()
+        );
+    }
+}
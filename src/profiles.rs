@@ -0,0 +1,399 @@
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderMap, StatusCode, header},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tracing::info;
+
+use crate::{
+    config::{AppConfig, RuleSettings, ScheduleEntry, Weekday},
+    server::AppState,
+};
+
+/// Wall-clock position used to evaluate schedules (UTC).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UtcClock {
+    pub weekday: Weekday,
+    pub minute: u32,
+}
+
+impl UtcClock {
+    pub fn now() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        Self::from_unix(secs)
+    }
+
+    fn from_unix(secs: u64) -> Self {
+        let days = secs / 86_400;
+        // 1970-01-01 was a Thursday (index 3 counting from Monday).
+        let weekday = Weekday::ALL[((days + 3) % 7) as usize];
+        let minute = ((secs % 86_400) / 60) as u32;
+        Self { weekday, minute }
+    }
+}
+
+impl ScheduleEntry {
+    fn matches(&self, clock: UtcClock) -> bool {
+        let day_ok = self.days.is_empty() || self.days.contains(&clock.weekday);
+        let time_ok = if self.start_minute < self.end_minute {
+            (self.start_minute..self.end_minute).contains(&clock.minute)
+        } else {
+            clock.minute >= self.start_minute || clock.minute < self.end_minute
+        };
+        day_ok && time_ok
+    }
+}
+
+/// The configured profiles plus the schedule that picks between them.
+#[derive(Debug, Clone)]
+pub struct ProfileSet {
+    profiles: Vec<Arc<RuleSettings>>,
+    default: String,
+    schedule: Vec<ScheduleEntry>,
+}
+
+impl ProfileSet {
+    pub fn from_config(config: &AppConfig) -> Self {
+        Self {
+            profiles: config.profiles.iter().cloned().map(Arc::new).collect(),
+            default: config.active_profile.clone(),
+            schedule: config.schedule.clone(),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Arc<RuleSettings>> {
+        self.profiles.iter().find(|profile| profile.name == name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.iter().map(|profile| profile.name.as_str())
+    }
+
+    /// First schedule entry covering `clock`, otherwise the configured default.
+    fn scheduled(&self, clock: UtcClock) -> (&str, ActivationSource) {
+        self.schedule
+            .iter()
+            .find(|entry| entry.matches(clock))
+            .map(|entry| (entry.profile.as_str(), ActivationSource::Schedule))
+            .unwrap_or((self.default.as_str(), ActivationSource::Default))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivationSource {
+    Default,
+    Schedule,
+    Manual,
+}
+
+impl ActivationSource {
+    fn as_str(self) -> &'static str {
+        match self {
+            ActivationSource::Default => "default",
+            ActivationSource::Schedule => "schedule",
+            ActivationSource::Manual => "manual",
+        }
+    }
+}
+
+pub struct Activation {
+    pub profile: Arc<RuleSettings>,
+    pub source: ActivationSource,
+}
+
+/// Manual profile switch and the last activation, kept across config reloads.
+///
+/// A manual switch holds until the schedule moves to a different profile.
+#[derive(Clone, Default)]
+pub struct ProfileSelector {
+    state: Arc<Mutex<SelectorState>>,
+}
+
+#[derive(Default)]
+struct SelectorState {
+    manual: Option<ManualSwitch>,
+    last_active: Option<String>,
+}
+
+struct ManualSwitch {
+    profile: String,
+    scheduled_at_switch: String,
+}
+
+impl ProfileSelector {
+    /// Resolves the active profile and logs `profile_activated` whenever it changes.
+    pub fn resolve(&self, set: &ProfileSet, clock: UtcClock) -> Activation {
+        let mut state = self.state.lock().expect("profile selector poisoned");
+        let (scheduled, scheduled_source) = set.scheduled(clock);
+
+        let expired = state.manual.as_ref().is_some_and(|manual| {
+            manual.scheduled_at_switch != scheduled || set.get(&manual.profile).is_none()
+        });
+        if expired {
+            state.manual = None;
+        }
+
+        let (name, source) = match &state.manual {
+            Some(manual) => (manual.profile.as_str(), ActivationSource::Manual),
+            None => (scheduled, scheduled_source),
+        };
+        let profile = set
+            .get(name)
+            .or_else(|| set.get(&set.default))
+            .unwrap_or(&set.profiles[0])
+            .clone();
+
+        if state.last_active.as_deref() != Some(profile.name.as_str()) {
+            info!(
+                profile = profile.name.as_str(),
+                source = source.as_str(),
+                "profile_activated"
+            );
+            state.last_active = Some(profile.name.clone());
+        }
+
+        Activation { profile, source }
+    }
+
+    /// Switches to `profile` until the schedule changes, or clears the switch with `None`.
+    pub fn switch(
+        &self,
+        set: &ProfileSet,
+        profile: Option<&str>,
+        clock: UtcClock,
+    ) -> Result<(), UnknownProfile> {
+        let mut state = self.state.lock().expect("profile selector poisoned");
+        match profile {
+            Some(name) => {
+                if set.get(name).is_none() {
+                    return Err(UnknownProfile(name.to_string()));
+                }
+                state.manual = Some(ManualSwitch {
+                    profile: name.to_string(),
+                    scheduled_at_switch: set.scheduled(clock).0.to_string(),
+                });
+                info!(profile = name, "profile_switched");
+            }
+            None => {
+                state.manual = None;
+                info!("profile_switch_cleared");
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct UnknownProfile(pub String);
+
+#[derive(Serialize)]
+pub struct ProfilesBody {
+    active: String,
+    source: ActivationSource,
+    profiles: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub struct SwitchRequest {
+    profile: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ErrorBody {
+    error: String,
+}
+
+pub async fn list_profiles_handler(State(state): State<Arc<AppState>>) -> Json<ProfilesBody> {
+    Json(profiles_body(&state))
+}
+
+pub async fn switch_profile_handler(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(request): Json<SwitchRequest>,
+) -> Result<Json<ProfilesBody>, (StatusCode, Json<ErrorBody>)> {
+    authorize(&state, &headers)?;
+
+    state
+        .analyzer
+        .switch_profile(request.profile.as_deref())
+        .map_err(|UnknownProfile(name)| {
+            error_response(StatusCode::NOT_FOUND, format!("unknown profile {name:?}"))
+        })?;
+
+    Ok(Json(profiles_body(&state)))
+}
+
+fn profiles_body(state: &AppState) -> ProfilesBody {
+    let (set, activation) = state.analyzer.profile_status();
+    ProfilesBody {
+        active: activation.profile.name.clone(),
+        source: activation.source,
+        profiles: set.names().map(str::to_string).collect(),
+    }
+}
+
+/// Admin endpoints need `Authorization: Bearer <admin token>` and are off without a token.
+pub fn authorize(
+    state: &AppState,
+    headers: &HeaderMap,
+) -> Result<(), (StatusCode, Json<ErrorBody>)> {
+    let Some(expected) = state.admin_token.as_deref() else {
+        return Err(error_response(
+            StatusCode::FORBIDDEN,
+            "admin API disabled: set admin.token".to_string(),
+        ));
+    };
+
+    let provided = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    if provided != Some(expected) {
+        return Err(error_response(
+            StatusCode::UNAUTHORIZED,
+            "missing or invalid bearer token".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn error_response(status: StatusCode, error: String) -> (StatusCode, Json<ErrorBody>) {
+    (status, Json(ErrorBody { error }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ActivationSource, ProfileSelector, ProfileSet, UtcClock};
+    use crate::config::{
+        Bounds, CurveSettings, RiskSettings, RuleSettings, ScheduleEntry, Weekday, WhaleSettings,
+    };
+    use std::{sync::Arc, time::Duration};
+
+    fn profile(name: &str) -> Arc<RuleSettings> {
+        Arc::new(RuleSettings {
+            name: name.to_string(),
+            market_cap: Bounds::default(),
+            liquidity: Bounds::default(),
+            volume_24h: Bounds::default(),
+            holders: Bounds::default(),
+            age: Bounds::default(),
+            growth: None,
+            volume_spike: None,
+            whale: WhaleSettings::default(),
+            curve: CurveSettings::default(),
+            risk: RiskSettings::default(),
+            event_types: Vec::new(),
+            launches_only: false,
+            cooldown: Duration::ZERO,
+        })
+    }
+
+    fn set() -> ProfileSet {
+        ProfileSet {
+            profiles: vec![profile("default"), profile("large"), profile("night")],
+            default: "default".to_string(),
+            schedule: vec![
+                ScheduleEntry {
+                    profile: "large".to_string(),
+                    days: vec![Weekday::Mon, Weekday::Tue],
+                    start_minute: 9 * 60,
+                    end_minute: 17 * 60,
+                },
+                ScheduleEntry {
+                    profile: "night".to_string(),
+                    days: Vec::new(),
+                    start_minute: 22 * 60,
+                    end_minute: 6 * 60,
+                },
+            ],
+        }
+    }
+
+    fn clock(weekday: Weekday, hour: u32, minute: u32) -> UtcClock {
+        UtcClock {
+            weekday,
+            minute: hour * 60 + minute,
+        }
+    }
+
+    #[test]
+    fn clock_from_unix_time() {
+        // 2024-01-01T10:30:00Z was a Monday.
+        assert_eq!(
+            clock(Weekday::Mon, 10, 30),
+            UtcClock::from_unix(1_704_105_000)
+        );
+    }
+
+    #[test]
+    fn schedule_picks_by_weekday_and_time() {
+        let set = set();
+        let selector = ProfileSelector::default();
+
+        let weekday = selector.resolve(&set, clock(Weekday::Tue, 9, 0));
+        assert_eq!("large", weekday.profile.name);
+        assert_eq!(ActivationSource::Schedule, weekday.source);
+
+        let weekend = selector.resolve(&set, clock(Weekday::Sat, 12, 0));
+        assert_eq!("default", weekend.profile.name);
+        assert_eq!(ActivationSource::Default, weekend.source);
+
+        assert_eq!(
+            "night",
+            selector
+                .resolve(&set, clock(Weekday::Sat, 23, 15))
+                .profile
+                .name
+        );
+        assert_eq!(
+            "night",
+            selector
+                .resolve(&set, clock(Weekday::Sun, 5, 59))
+                .profile
+                .name
+        );
+        assert_eq!(
+            "default",
+            selector
+                .resolve(&set, clock(Weekday::Sun, 6, 0))
+                .profile
+                .name
+        );
+    }
+
+    #[test]
+    fn manual_switch_holds_until_schedule_changes() {
+        let set = set();
+        let selector = ProfileSelector::default();
+        let noon = clock(Weekday::Sat, 12, 0);
+
+        selector.switch(&set, Some("large"), noon).expect("switch");
+        let switched = selector.resolve(&set, clock(Weekday::Sat, 18, 0));
+        assert_eq!("large", switched.profile.name);
+        assert_eq!(ActivationSource::Manual, switched.source);
+
+        let night = selector.resolve(&set, clock(Weekday::Sat, 22, 30));
+        assert_eq!("night", night.profile.name);
+        assert_eq!(ActivationSource::Schedule, night.source);
+    }
+
+    #[test]
+    fn rejects_unknown_profile() {
+        let selector = ProfileSelector::default();
+        let err = selector
+            .switch(&set(), Some("missing"), clock(Weekday::Mon, 0, 0))
+            .expect_err("unknown profile");
+        assert_eq!("missing", err.0);
+    }
+}
@@ -0,0 +1,277 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::helius::{HeliusWebhook, Instruction, encode_base58};
+
+pub const PUMP_FUN_PROGRAM: &str = "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P";
+
+/// Prefix of the self-invoked instruction Anchor uses to emit events.
+const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+const TRADE_EVENT: [u8; 8] = [189, 219, 127, 211, 78, 230, 97, 238];
+/// `withdraw` moves a finished curve's liquidity to Raydium; `migrate` to PumpSwap.
+const MIGRATIONS: [([u8; 8], &str); 2] = [
+    ([183, 18, 70, 156, 148, 109, 161, 34], "Raydium"),
+    ([155, 234, 231, 146, 236, 158, 162, 30], "PumpSwap"),
+];
+/// Account position of the mint in both migration instructions.
+const MIGRATION_MINT_ACCOUNT: usize = 2;
+
+/// Every curve starts with these virtual token reserves and sells this many
+/// tokens before it completes (6 decimals).
+const INITIAL_VIRTUAL_TOKEN_RESERVES: u64 = 1_073_000_000_000_000;
+const INITIAL_REAL_TOKEN_RESERVES: u64 = 793_100_000_000_000;
+const TOKEN_DECIMALS: f64 = 1e6;
+const LAMPORTS_PER_SOL: f64 = 1e9;
+
+/// Curves without trades for this long are forgotten.
+const CURVE_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A pump.fun bonding-curve transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CurveActivity {
+    Trade(CurveTrade),
+    Migrated {
+        mint: String,
+        destination: &'static str,
+    },
+}
+
+impl CurveActivity {
+    pub fn mint(&self) -> &str {
+        match self {
+            CurveActivity::Trade(trade) => &trade.mint,
+            CurveActivity::Migrated { mint, .. } => mint,
+        }
+    }
+}
+
+/// A buy or sell on the curve, from the program's `TradeEvent`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurveTrade {
+    pub mint: String,
+    pub is_buy: bool,
+    pub sol: f64,
+    pub tokens: f64,
+    pub virtual_token_reserves: u64,
+}
+
+impl CurveTrade {
+    /// Share of the curve's sellable tokens already sold, 0–100.
+    pub fn fill_percent(&self) -> f64 {
+        let sold = INITIAL_VIRTUAL_TOKEN_RESERVES.saturating_sub(self.virtual_token_reserves);
+        (sold as f64 / INITIAL_REAL_TOKEN_RESERVES as f64 * 100.0).min(100.0)
+    }
+}
+
+/// Finds the last curve trade or a migration in `event`; a migration wins.
+pub fn parse(event: &HeliusWebhook) -> Option<CurveActivity> {
+    let pump = || {
+        event
+            .all_instructions()
+            .filter(|instruction| instruction.program_id == PUMP_FUN_PROGRAM)
+    };
+    pump()
+        .find_map(parse_migration)
+        .or_else(|| pump().filter_map(parse_trade).last())
+}
+
+fn parse_migration(instruction: &Instruction) -> Option<CurveActivity> {
+    let data = instruction.data_bytes()?;
+    let (_, destination) = MIGRATIONS
+        .iter()
+        .find(|(discriminator, _)| data.starts_with(discriminator))?;
+    Some(CurveActivity::Migrated {
+        mint: instruction.accounts.get(MIGRATION_MINT_ACCOUNT)?.clone(),
+        destination,
+    })
+}
+
+/// `TradeEvent` layout: mint, sol_amount, token_amount, is_buy, user, timestamp,
+/// virtual_sol_reserves, virtual_token_reserves; later fields are ignored.
+fn parse_trade(instruction: &Instruction) -> Option<CurveActivity> {
+    let data = instruction.data_bytes()?;
+    let body = data
+        .strip_prefix(&EVENT_IX_TAG)?
+        .strip_prefix(&TRADE_EVENT)?;
+    let u64_at = |offset: usize| {
+        body.get(offset..offset + 8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().expect("8 bytes")))
+    };
+    Some(CurveActivity::Trade(CurveTrade {
+        mint: encode_base58(body.get(..32)?),
+        sol: u64_at(32)? as f64 / LAMPORTS_PER_SOL,
+        tokens: u64_at(40)? as f64 / TOKEN_DECIMALS,
+        is_buy: *body.get(48)? != 0,
+        virtual_token_reserves: u64_at(97)?,
+    }))
+}
+
+/// What changed on a curve with this event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CurveUpdate {
+    Progress {
+        fill_percent: f64,
+        /// Fill before this trade; `None` for the first trade seen.
+        previous_percent: Option<f64>,
+    },
+    Migrated {
+        destination: &'static str,
+        /// Last fill seen before the migration.
+        last_percent: Option<f64>,
+    },
+}
+
+/// Latest bonding-curve fill per pump.fun mint.
+#[derive(Clone)]
+pub struct BondingCurves {
+    inner: Arc<Mutex<Curves>>,
+}
+
+struct Curves {
+    by_mint: HashMap<String, (Instant, f64)>,
+    last_sweep: Instant,
+}
+
+impl Default for BondingCurves {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Curves {
+                by_mint: HashMap::new(),
+                last_sweep: Instant::now(),
+            })),
+        }
+    }
+}
+
+impl BondingCurves {
+    pub fn record(&self, activity: &CurveActivity) -> CurveUpdate {
+        self.record_at(activity, Instant::now())
+    }
+
+    fn record_at(&self, activity: &CurveActivity, now: Instant) -> CurveUpdate {
+        let mut inner = self.inner.lock().expect("bonding curves poisoned");
+        if now.duration_since(inner.last_sweep) >= SWEEP_INTERVAL {
+            inner
+                .by_mint
+                .retain(|_, (at, _)| now.duration_since(*at) < CURVE_RETENTION);
+            inner.last_sweep = now;
+        }
+
+        match activity {
+            CurveActivity::Trade(trade) => {
+                let fill_percent = trade.fill_percent();
+                let previous = inner
+                    .by_mint
+                    .insert(trade.mint.clone(), (now, fill_percent));
+                CurveUpdate::Progress {
+                    fill_percent,
+                    previous_percent: previous.map(|(_, fill)| fill),
+                }
+            }
+            // A migrated token trades on its new pool; the curve is done.
+            CurveActivity::Migrated { mint, destination } => CurveUpdate::Migrated {
+                destination,
+                last_percent: inner.by_mint.remove(mint).map(|(_, fill)| fill),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BondingCurves, CurveActivity, CurveUpdate, EVENT_IX_TAG, PUMP_FUN_PROGRAM, TRADE_EVENT,
+        parse,
+    };
+    use crate::helius::{HeliusWebhook, decode_base58, encode_base58};
+    use std::time::{Duration, Instant};
+
+    const MINT: &str = "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263";
+
+    fn trade_event_data(virtual_token_reserves: u64) -> String {
+        let mut data = [EVENT_IX_TAG, TRADE_EVENT].concat();
+        data.extend(decode_base58(MINT).expect("mint"));
+        data.extend(2_000_000_000u64.to_le_bytes()); // 2 SOL
+        data.extend(50_000_000_000_000u64.to_le_bytes()); // 50M tokens
+        data.push(1);
+        data.extend([7; 32]); // user
+        data.extend(1_700_000_000i64.to_le_bytes());
+        data.extend(40_000_000_000u64.to_le_bytes());
+        data.extend(virtual_token_reserves.to_le_bytes());
+        encode_base58(&data)
+    }
+
+    fn event(instructions: serde_json::Value) -> HeliusWebhook {
+        serde_json::from_value(serde_json::json!({
+            "signature": "sig",
+            "type": "SWAP",
+            "instructions": instructions
+        }))
+        .expect("webhook")
+    }
+
+    #[test]
+    fn parses_trade_event_and_tracks_fill() {
+        // 793.1M sellable tokens; 80% sold leaves 1073M - 634.48M virtual.
+        let activity = parse(&event(serde_json::json!([{
+            "programId": PUMP_FUN_PROGRAM,
+            "accounts": ["Global", "Fee", MINT],
+            "data": "",
+            "innerInstructions": [{
+                "programId": PUMP_FUN_PROGRAM,
+                "data": trade_event_data(438_520_000_000_000)
+            }]
+        }])))
+        .expect("curve trade");
+        let CurveActivity::Trade(trade) = &activity else {
+            panic!("expected a trade, got {activity:?}");
+        };
+        assert_eq!(MINT, trade.mint);
+        assert!(trade.is_buy);
+        assert_eq!(2.0, trade.sol);
+        assert_eq!(50_000_000.0, trade.tokens);
+        assert!((trade.fill_percent() - 80.0).abs() < 1e-9);
+
+        let curves = BondingCurves::default();
+        let start = Instant::now();
+        assert!(matches!(
+            curves.record_at(&activity, start),
+            CurveUpdate::Progress {
+                previous_percent: None,
+                ..
+            }
+        ));
+        let migration = CurveActivity::Migrated {
+            mint: MINT.to_string(),
+            destination: "PumpSwap",
+        };
+        assert_eq!(
+            CurveUpdate::Migrated {
+                destination: "PumpSwap",
+                last_percent: Some(trade.fill_percent()),
+            },
+            curves.record_at(&migration, start + Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn parses_migration_instruction() {
+        let activity = parse(&event(serde_json::json!([{
+            "programId": PUMP_FUN_PROGRAM,
+            "accounts": ["Global", "Authority", MINT, "Curve111"],
+            "data": encode_base58(&[183, 18, 70, 156, 148, 109, 161, 34])
+        }])));
+        assert_eq!(
+            Some(CurveActivity::Migrated {
+                mint: MINT.to_string(),
+                destination: "Raydium",
+            }),
+            activity
+        );
+        assert_eq!(None, parse(&event(serde_json::json!([]))));
+    }
+}
@@ -0,0 +1,169 @@
+use std::path::{Path, PathBuf};
+use tokio::signal::unix::{SignalKind, signal};
+use tracing::{error, info, warn};
+
+use crate::{
+    analyzer::Analyzer,
+    config::{AppConfig, ConfigChange},
+    watch::FileStamps,
+};
+
+/// Key prefixes applied to the running analyzer; anything else needs a restart.
+const RELOADABLE_PREFIXES: &[&str] = &[
+    "filter.",
+    "alerts.",
+    "profiles.",
+    "rules.",
+    "schedule",
+    "active_profile",
+    "watchlist.wallets",
+    "mints.watched",
+    "mints.ignored",
+];
+
+/// Reloads the config file when it changes on disk or the process receives SIGHUP.
+///
+/// Invalid files are rejected and the previous configuration stays active.
+pub async fn watch_config(path: PathBuf, mut current: AppConfig, analyzer: Analyzer) {
+    let mut hangup = match signal(SignalKind::hangup()) {
+        Ok(hangup) => Some(hangup),
+        Err(err) => {
+            warn!(error = ?err, "sighup_handler_unavailable");
+            None
+        }
+    };
+    let mut stamps = FileStamps::new(vec![path.clone()]);
+    let mut interval = tokio::time::interval(current.reload_interval);
+    interval.tick().await;
+
+    loop {
+        let trigger = tokio::select! {
+            _ = interval.tick() => {
+                if !stamps.changed() {
+                    continue;
+                }
+                "file_changed"
+            }
+            Some(()) = async {
+                match hangup.as_mut() {
+                    Some(hangup) => hangup.recv().await,
+                    None => std::future::pending().await,
+                }
+            } => "sighup",
+        };
+
+        reload_once(&path, &mut current, &analyzer, trigger);
+    }
+}
+
+fn reload_once(path: &Path, current: &mut AppConfig, analyzer: &Analyzer, trigger: &str) {
+    let next = match AppConfig::load(Some(path)) {
+        Ok(next) => next,
+        Err(err) => {
+            error!(
+                path = %path.display(),
+                trigger = trigger,
+                error = %err,
+                "config_reload_rejected"
+            );
+            return;
+        }
+    };
+
+    let changes = current.diff(&next);
+    if changes.is_empty() {
+        info!(path = %path.display(), trigger = trigger, "config_reload_unchanged");
+        return;
+    }
+
+    for change in &changes {
+        let reloadable = is_reloadable(change);
+        info!(
+            key = change.key.as_str(),
+            old = change.old.as_deref().unwrap_or(""),
+            new = change.new.as_deref().unwrap_or(""),
+            applied = reloadable,
+            "config_changed"
+        );
+        if !reloadable {
+            warn!(key = change.key.as_str(), "config_change_requires_restart");
+        }
+    }
+
+    // Only what was applied is kept, so restart-only changes show up again on the
+    // next reload with the value actually in use as `old`.
+    current.apply_reloadable(&next);
+    analyzer.apply_config(current);
+    info!(
+        path = %path.display(),
+        trigger = trigger,
+        changed_keys = changes.len(),
+        "config_reloaded"
+    );
+}
+
+fn is_reloadable(change: &ConfigChange) -> bool {
+    RELOADABLE_PREFIXES
+        .iter()
+        .any(|prefix| change.key.starts_with(prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_reloadable;
+    use crate::config::{AppConfig, ConfigChange};
+
+    fn change(key: &str) -> ConfigChange {
+        ConfigChange {
+            key: key.to_string(),
+            old: None,
+            new: None,
+        }
+    }
+
+    #[test]
+    fn only_filter_profile_and_alert_keys_apply_live() {
+        assert!(is_reloadable(&change("filter.market_cap.min_usd")));
+        assert!(is_reloadable(&change("alerts.latency_footer")));
+        assert!(is_reloadable(&change("rules.micro.growth.percent")));
+        assert!(!is_reloadable(&change("server.port")));
+        assert!(!is_reloadable(&change("moralis.base_url")));
+    }
+
+    #[test]
+    fn keeps_restart_only_changes_pending() {
+        let config = |port, min_usd| {
+            AppConfig::from_toml(&format!(
+                r#"
+[moralis]
+api_key = "key"
+
+[telegram]
+bot_token = "token"
+chat_id = "42"
+
+[server]
+port = {port}
+
+[filter.market_cap]
+min_usd = {min_usd}
+"#
+            ))
+            .expect("config")
+        };
+        let mut current = config(3000, 1000);
+        let next = config(4000, 2000);
+
+        current.apply_reloadable(&next);
+        let pending = current.diff(&next);
+        assert_eq!(
+            vec![ConfigChange {
+                key: "server.port".to_string(),
+                old: Some("3000".to_string()),
+                new: Some("4000".to_string()),
+            }],
+            pending
+        );
+        assert!(pending.iter().all(|change| !is_reloadable(change)));
+    }
+}
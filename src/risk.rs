@@ -0,0 +1,214 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::rpc::{MintAccount, RpcClient, RpcError, TokenAccountBalance};
+
+/// Number of largest token accounts summed for the concentration check.
+const TOP_HOLDERS: usize = 10;
+
+const SYSTEM_PROGRAM: &str = "11111111111111111111111111111111";
+/// AMM authorities owning pool vaults without being program accounts themselves.
+const POOL_AUTHORITIES: [&str; 2] = [
+    // Raydium AMM v4
+    "5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1",
+    // Raydium CPMM
+    "GpMZbSM2GgvTKHJirzeGfMFoaZ8UR2X7F4v8vHTvxFbL",
+];
+
+/// On-chain rug-risk flags for one mint.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RiskReport {
+    /// The mint authority can still create new supply.
+    pub mint_authority: bool,
+    /// The freeze authority can still freeze holders' token accounts.
+    pub freeze_authority: bool,
+    /// Share of supply held by the ten largest token accounts, in percent; pool vaults
+    /// and bonding curves are not holders and are left out.
+    pub top10_share_percent: Option<f64>,
+}
+
+impl RiskReport {
+    /// `pools` lists token accounts in `largest` that belong to a pool or bonding curve.
+    pub fn from_chain(
+        mint: &MintAccount,
+        largest: &[TokenAccountBalance],
+        pools: &HashSet<String>,
+    ) -> Self {
+        let holders: Vec<&TokenAccountBalance> = largest
+            .iter()
+            .filter(|account| !pools.contains(&account.address))
+            .collect();
+        Self {
+            mint_authority: mint.mint_authority.is_some(),
+            freeze_authority: mint.freeze_authority.is_some(),
+            top10_share_percent: top_holder_share(mint.supply, &holders),
+        }
+    }
+
+    /// One-line summary for logs, e.g. `mint authority revoked, freeze authority enabled, top10 34.20%`.
+    pub fn summary(&self) -> String {
+        let state = |enabled: bool| if enabled { "enabled" } else { "revoked" };
+        let top10 = self
+            .top10_share_percent
+            .map(|share| format!("{share:.2}%"))
+            .unwrap_or_else(|| "n/a".to_string());
+        format!(
+            "mint authority {}, freeze authority {}, top10 {top10}",
+            state(self.mint_authority),
+            state(self.freeze_authority),
+        )
+    }
+}
+
+/// Fetches the mint account and largest holders concurrently, then the owners of those
+/// holders to tell pools and curves apart from wallets.
+///
+/// Returns `None` when `mint` is not an SPL token mint.
+pub async fn assess(rpc: &RpcClient, mint: &str) -> Result<Option<RiskReport>, RpcError> {
+    let (account, largest) = tokio::join!(
+        rpc.get_mint_account(mint),
+        rpc.get_token_largest_accounts(mint)
+    );
+    let Some(account) = account? else {
+        return Ok(None);
+    };
+    let largest = largest?;
+    let pools = pool_accounts(rpc, &largest).await?;
+    Ok(Some(RiskReport::from_chain(&account, &largest, &pools)))
+}
+
+/// Token accounts in `largest` owned by a known AMM authority or by a program account,
+/// such as a pool or the pump.fun bonding curve, rather than by a wallet.
+async fn pool_accounts(
+    rpc: &RpcClient,
+    largest: &[TokenAccountBalance],
+) -> Result<HashSet<String>, RpcError> {
+    if largest.is_empty() {
+        return Ok(HashSet::new());
+    }
+    let addresses: Vec<String> = largest
+        .iter()
+        .map(|account| account.address.clone())
+        .collect();
+    let owners: Vec<Option<String>> = rpc
+        .get_multiple_accounts(&addresses)
+        .await?
+        .into_iter()
+        .map(|account| account.and_then(|account| account.token_owner()))
+        .collect();
+
+    let mut distinct: Vec<String> = owners.iter().flatten().cloned().collect();
+    distinct.sort();
+    distinct.dedup();
+    let programs: HashMap<String, String> = distinct
+        .iter()
+        .cloned()
+        .zip(rpc.get_multiple_accounts(&distinct).await?)
+        .filter_map(|(owner, account)| Some((owner, account?.owner)))
+        .collect();
+
+    Ok(addresses
+        .into_iter()
+        .zip(owners)
+        .filter(|(_, owner)| {
+            owner
+                .as_deref()
+                .is_some_and(|owner| is_pool_owner(owner, programs.get(owner).map(String::as_str)))
+        })
+        .map(|(address, _)| address)
+        .collect())
+}
+
+/// Wallets are owned by the system program; an owner held by any other program is a
+/// pool, curve or vault.
+fn is_pool_owner(owner: &str, owner_program: Option<&str>) -> bool {
+    POOL_AUTHORITIES.contains(&owner)
+        || owner_program.is_some_and(|program| program != SYSTEM_PROGRAM)
+}
+
+/// Percentage of `supply` held by the largest accounts; `None` for an empty supply.
+fn top_holder_share(supply: u64, largest: &[&TokenAccountBalance]) -> Option<f64> {
+    if supply == 0 {
+        return None;
+    }
+    let mut amounts: Vec<u64> = largest.iter().map(|account| account.amount).collect();
+    amounts.sort_unstable_by(|a, b| b.cmp(a));
+    let held: u128 = amounts
+        .iter()
+        .take(TOP_HOLDERS)
+        .map(|amount| u128::from(*amount))
+        .sum();
+    Some(held as f64 / supply as f64 * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RiskReport, SYSTEM_PROGRAM, is_pool_owner, top_holder_share};
+    use crate::{
+        pumpfun::PUMP_FUN_PROGRAM,
+        rpc::{MintAccount, TokenAccountBalance},
+    };
+    use std::collections::HashSet;
+
+    fn balances(amounts: &[u64]) -> Vec<TokenAccountBalance> {
+        amounts
+            .iter()
+            .enumerate()
+            .map(|(index, amount)| TokenAccountBalance {
+                address: format!("Account{index}"),
+                amount: *amount,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sums_only_the_ten_largest_accounts() {
+        let mut amounts = vec![1; 5];
+        amounts.extend([100, 50, 50, 40, 30, 20, 10, 10, 10, 10, 5]);
+        let balances = balances(&amounts);
+        let holders: Vec<&TokenAccountBalance> = balances.iter().collect();
+
+        assert_eq!(Some(33.0), top_holder_share(1_000, &holders));
+        assert_eq!(None, top_holder_share(0, &holders));
+    }
+
+    #[test]
+    fn leaves_pool_and_curve_accounts_out_of_concentration() {
+        let mint = MintAccount {
+            mint_authority: None,
+            freeze_authority: None,
+            supply: 1_000,
+        };
+        // The curve holds most of the supply; two wallets hold the rest.
+        let largest = balances(&[800, 30, 20]);
+        let pools = HashSet::from(["Account0".to_string()]);
+
+        let report = RiskReport::from_chain(&mint, &largest, &pools);
+        assert_eq!(Some(5.0), report.top10_share_percent);
+        let unfiltered = RiskReport::from_chain(&mint, &largest, &HashSet::new());
+        assert_eq!(Some(85.0), unfiltered.top10_share_percent);
+
+        assert!(is_pool_owner("BondingCurve111", Some(PUMP_FUN_PROGRAM)));
+        assert!(is_pool_owner(
+            "5Q544fKrFoe6tsEbD7S8EmxGTJYAKtTVhAW5Q5pge4j1",
+            None
+        ));
+        assert!(!is_pool_owner("Wallet111", Some(SYSTEM_PROGRAM)));
+        assert!(!is_pool_owner("Wallet111", None));
+    }
+
+    #[test]
+    fn flags_live_authorities() {
+        let mint = MintAccount {
+            mint_authority: Some("authority".to_string()),
+            freeze_authority: None,
+            supply: 200,
+        };
+        let report = RiskReport::from_chain(&mint, &balances(&[150]), &HashSet::new());
+
+        assert!(report.mint_authority && !report.freeze_authority);
+        assert_eq!(
+            "mint authority enabled, freeze authority revoked, top10 75.00%",
+            report.summary()
+        );
+    }
+}
@@ -0,0 +1,631 @@
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, de::DeserializeOwned};
+use serde_json::{Value, json};
+use std::time::Duration;
+use thiserror::Error;
+use tracing::warn;
+
+use crate::config::RpcSettings;
+
+/// Solana JSON-RPC client for on-chain token lookups.
+///
+/// Timeouts, connection errors, 429 and 5xx responses are retried with
+/// exponential backoff; JSON-RPC errors are returned as-is.
+#[derive(Clone)]
+pub struct RpcClient {
+    http: Client,
+    url: String,
+    max_retries: u32,
+    retry_backoff: Duration,
+}
+
+impl RpcClient {
+    pub fn new(settings: &RpcSettings) -> Self {
+        let http = reqwest::Client::builder()
+            .no_proxy()
+            .timeout(settings.timeout)
+            .build()
+            .expect("failed to build Solana RPC HTTP client");
+
+        Self {
+            http,
+            url: settings.url.clone(),
+            max_retries: settings.max_retries,
+            retry_backoff: settings.retry_backoff,
+        }
+    }
+
+    /// `getAccountInfo` for an SPL token mint, decoded with `jsonParsed`.
+    ///
+    /// Returns `None` when the account does not exist or is not a mint.
+    pub async fn get_mint_account(&self, mint: &str) -> Result<Option<MintAccount>, RpcError> {
+        let response: WithContext<Option<ParsedAccount>> = self
+            .call(
+                "getAccountInfo",
+                json!([mint, {"encoding": "jsonParsed", "commitment": "confirmed"}]),
+            )
+            .await?;
+
+        // Accounts the node cannot parse come back base64-encoded instead.
+        Ok(response
+            .value
+            .and_then(|account| serde_json::from_value::<ParsedEnvelope>(account.data).ok())
+            .filter(|envelope| envelope.parsed.kind == "mint")
+            .and_then(|envelope| serde_json::from_value(envelope.parsed.info).ok()))
+    }
+
+    /// `getTokenSupply`: total supply of `mint` with its decimals.
+    pub async fn get_token_supply(&self, mint: &str) -> Result<TokenAmount, RpcError> {
+        let response: WithContext<TokenAmount> = self
+            .call("getTokenSupply", json!([mint, {"commitment": "confirmed"}]))
+            .await?;
+        Ok(response.value)
+    }
+
+    /// `getTokenLargestAccounts`: up to 20 token accounts holding the most of `mint`.
+    pub async fn get_token_largest_accounts(
+        &self,
+        mint: &str,
+    ) -> Result<Vec<TokenAccountBalance>, RpcError> {
+        let response: WithContext<Vec<TokenAccountBalance>> = self
+            .call(
+                "getTokenLargestAccounts",
+                json!([mint, {"commitment": "confirmed"}]),
+            )
+            .await?;
+        Ok(response.value)
+    }
+
+    /// `getMultipleAccounts` with `jsonParsed`, in the order of `addresses`; `None` where
+    /// an account does not exist.
+    pub async fn get_multiple_accounts(
+        &self,
+        addresses: &[String],
+    ) -> Result<Vec<Option<AccountInfo>>, RpcError> {
+        let response: WithContext<Vec<Option<AccountInfo>>> = self
+            .call(
+                "getMultipleAccounts",
+                json!([addresses, {"encoding": "jsonParsed", "commitment": "confirmed"}]),
+            )
+            .await?;
+        Ok(response.value)
+    }
+
+    /// `getTransaction` with parsed token balances; `None` when the node does not know it.
+    #[cfg_attr(
+        not(test),
+        expect(dead_code, reason = "history lookups have no caller outside tests yet")
+    )]
+    pub async fn get_transaction(
+        &self,
+        signature: &str,
+    ) -> Result<Option<TransactionDetails>, RpcError> {
+        self.call_nullable(
+            "getTransaction",
+            json!([
+                signature,
+                {
+                    "encoding": "jsonParsed",
+                    "commitment": "confirmed",
+                    "maxSupportedTransactionVersion": 0,
+                }
+            ]),
+        )
+        .await
+    }
+
+    /// `getSignaturesForAddress`: newest first, at most `limit`, older than `before` if set.
+    #[cfg_attr(
+        not(test),
+        expect(dead_code, reason = "history lookups have no caller outside tests yet")
+    )]
+    pub async fn get_signatures_for_address(
+        &self,
+        address: &str,
+        limit: usize,
+        before: Option<&str>,
+    ) -> Result<Vec<SignatureInfo>, RpcError> {
+        let mut options = json!({"limit": limit, "commitment": "confirmed"});
+        if let Some(before) = before {
+            options["before"] = json!(before);
+        }
+        self.call("getSignaturesForAddress", json!([address, options]))
+            .await
+    }
+
+    async fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T, RpcError> {
+        self.call_nullable(method, params)
+            .await?
+            .ok_or(RpcError::EmptyResponse)
+    }
+
+    /// Like `call`, but a `null` result is `Ok(None)` rather than an error.
+    async fn call_nullable<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> Result<Option<T>, RpcError> {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let mut attempt = 0;
+        loop {
+            match self.send(&body).await {
+                Err(err) if attempt < self.max_retries && err.is_retryable() => {
+                    let delay = self.retry_backoff * 2u32.saturating_pow(attempt);
+                    attempt += 1;
+                    warn!(
+                        method = method,
+                        attempt = attempt,
+                        delay_ms = delay.as_millis() as u64,
+                        error = %err,
+                        "rpc_retry"
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn send<T: DeserializeOwned>(&self, body: &Value) -> Result<Option<T>, RpcError> {
+        let response = self.http.post(&self.url).json(body).send().await?;
+        if !response.status().is_success() {
+            return Err(RpcError::HttpStatus(response.status()));
+        }
+
+        let payload: RpcResponse<T> = response.json().await?;
+        match payload.error {
+            Some(error) => Err(RpcError::Rpc {
+                code: error.code,
+                message: error.message,
+            }),
+            None => Ok(payload.result),
+        }
+    }
+}
+
+/// Parsed SPL mint state; amounts are raw base units.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MintAccount {
+    #[serde(default)]
+    pub mint_authority: Option<String>,
+    #[serde(default)]
+    pub freeze_authority: Option<String>,
+    #[serde(deserialize_with = "u64_from_string")]
+    pub supply: u64,
+}
+
+/// One token account's balance in raw base units.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenAccountBalance {
+    /// The token account, not the wallet owning it.
+    pub address: String,
+    #[serde(deserialize_with = "u64_from_string")]
+    pub amount: u64,
+}
+
+/// Any account: the program owning it and its data as the node returned it.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountInfo {
+    pub owner: String,
+    data: Value,
+}
+
+impl AccountInfo {
+    /// Wallet or program address an SPL token account belongs to; `None` for other accounts.
+    pub fn token_owner(&self) -> Option<String> {
+        let envelope: ParsedEnvelope = serde_json::from_value(self.data.clone()).ok()?;
+        if envelope.parsed.kind != "account" {
+            return None;
+        }
+        envelope
+            .parsed
+            .info
+            .get("owner")?
+            .as_str()
+            .map(str::to_string)
+    }
+}
+
+/// A raw token amount and the decimals needed to scale it.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct TokenAmount {
+    #[serde(deserialize_with = "u64_from_string")]
+    pub amount: u64,
+    pub decimals: u8,
+}
+
+impl TokenAmount {
+    /// The amount in whole tokens.
+    pub fn ui_amount(&self) -> f64 {
+        self.amount as f64 / 10f64.powi(i32::from(self.decimals))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    not(test),
+    expect(dead_code, reason = "returned only by `get_transaction`")
+)]
+pub struct TransactionDetails {
+    pub slot: u64,
+    #[serde(default)]
+    pub block_time: Option<i64>,
+    #[serde(default)]
+    pub meta: Option<TransactionMeta>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[expect(dead_code, reason = "returned only by `get_transaction`")]
+pub struct TransactionMeta {
+    /// Set when the transaction failed on chain.
+    #[serde(default)]
+    pub err: Option<Value>,
+    pub fee: u64,
+    #[serde(default)]
+    pub pre_token_balances: Vec<TokenBalance>,
+    #[serde(default)]
+    pub post_token_balances: Vec<TokenBalance>,
+}
+
+/// A token account's balance before or after a transaction.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[expect(dead_code, reason = "returned only by `get_transaction`")]
+pub struct TokenBalance {
+    pub account_index: usize,
+    pub mint: String,
+    #[serde(default)]
+    pub owner: Option<String>,
+    pub ui_token_amount: TokenAmount,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[expect(dead_code, reason = "returned only by `get_signatures_for_address`")]
+pub struct SignatureInfo {
+    pub signature: String,
+    pub slot: u64,
+    #[serde(default)]
+    pub block_time: Option<i64>,
+    /// Set when the transaction failed on chain.
+    #[serde(default)]
+    pub err: Option<Value>,
+}
+
+#[derive(Deserialize)]
+struct RpcResponse<T> {
+    result: Option<T>,
+    error: Option<RpcErrorBody>,
+}
+
+#[derive(Deserialize)]
+struct RpcErrorBody {
+    code: i64,
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct WithContext<T> {
+    value: T,
+}
+
+#[derive(Deserialize)]
+struct ParsedAccount {
+    data: Value,
+}
+
+#[derive(Deserialize)]
+struct ParsedEnvelope {
+    parsed: ParsedData,
+}
+
+#[derive(Deserialize)]
+struct ParsedData {
+    #[serde(rename = "type")]
+    kind: String,
+    info: Value,
+}
+
+/// RPC nodes encode u64 amounts as strings to stay exact in JSON.
+fn u64_from_string<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    let text = String::deserialize(deserializer)?;
+    text.parse().map_err(serde::de::Error::custom)
+}
+
+#[derive(Debug, Error)]
+pub enum RpcError {
+    #[error("solana rpc request failed: {0}")]
+    Transport(#[from] reqwest::Error),
+    #[error("solana rpc returned non-success status {0}")]
+    HttpStatus(StatusCode),
+    #[error("solana rpc error {code}: {message}")]
+    Rpc { code: i64, message: String },
+    #[error("solana rpc response had neither result nor error")]
+    EmptyResponse,
+}
+
+impl RpcError {
+    /// Short label for metrics: the HTTP status, the JSON-RPC error code, or the failure kind.
+    pub fn status_label(&self) -> String {
+        match self {
+            RpcError::Transport(err) if err.is_timeout() => "timeout".to_string(),
+            RpcError::Transport(_) => "transport".to_string(),
+            RpcError::HttpStatus(status) => status.as_u16().to_string(),
+            RpcError::Rpc { code, .. } => code.to_string(),
+            RpcError::EmptyResponse => "empty".to_string(),
+        }
+    }
+
+    /// Failures another attempt may fix; malformed responses and RPC errors are final.
+    fn is_retryable(&self) -> bool {
+        match self {
+            RpcError::Transport(err) => !err.is_decode(),
+            RpcError::HttpStatus(status) => {
+                *status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+            }
+            RpcError::Rpc { .. } | RpcError::EmptyResponse => false,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{RpcClient, RpcError, TokenAmount};
+    use crate::config::RpcSettings;
+    use axum::{Json, Router, http::StatusCode, routing::post};
+    use serde_json::{Value, json};
+    use std::{
+        sync::{
+            Arc, Mutex,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
+
+    /// Serves `respond(call_index, request)` as a JSON-RPC endpoint on a random local port,
+    /// answering each request after `delay`.
+    pub(crate) async fn mock_rpc<F>(delay: Duration, respond: F) -> String
+    where
+        F: Fn(usize, &Value) -> (StatusCode, Value) + Clone + Send + Sync + 'static,
+    {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = Router::new().route(
+            "/",
+            post(move |Json(request): Json<Value>| async move {
+                let call = calls.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(delay).await;
+                let (status, body) = respond(call, &request);
+                (status, Json(body))
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock rpc");
+        let addr = listener.local_addr().expect("mock rpc addr");
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        format!("http://{addr}/")
+    }
+
+    fn client(url: String, timeout: Duration, max_retries: u32) -> RpcClient {
+        RpcClient::new(&RpcSettings {
+            url,
+            timeout,
+            max_retries,
+            retry_backoff: Duration::from_millis(1),
+        })
+    }
+
+    pub(crate) fn result(value: Value) -> (StatusCode, Value) {
+        (
+            StatusCode::OK,
+            json!({"jsonrpc": "2.0", "id": 1, "result": value}),
+        )
+    }
+
+    #[tokio::test]
+    async fn decodes_mint_supply_and_largest_accounts() {
+        let url = mock_rpc(Duration::ZERO, |_, request| {
+            match request["method"].as_str() {
+                Some("getAccountInfo") => result(json!({
+                    "context": {"slot": 1},
+                    "value": {"data": {"program": "spl-token", "parsed": {
+                        "type": "mint",
+                        "info": {
+                            "mintAuthority": null,
+                            "freezeAuthority": "Freeze111",
+                            "supply": "1000000000",
+                            "decimals": 6,
+                            "isInitialized": true
+                        }
+                    }}}
+                })),
+                Some("getTokenSupply") => result(json!({
+                    "context": {"slot": 1},
+                    "value": {"amount": "1000000000", "decimals": 6, "uiAmount": 1000.0}
+                })),
+                Some("getTokenLargestAccounts") => result(json!({
+                    "context": {"slot": 1},
+                    "value": [{"address": "Acct1", "amount": "400000000", "decimals": 6}]
+                })),
+                Some("getMultipleAccounts") => result(json!({
+                    "context": {"slot": 1},
+                    "value": [
+                        {
+                            "owner": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                            "data": {"program": "spl-token", "parsed": {
+                                "type": "account",
+                                "info": {"mint": "Mint111", "owner": "Wallet111"}
+                            }}
+                        },
+                        null
+                    ]
+                })),
+                _ => (StatusCode::BAD_REQUEST, json!({})),
+            }
+        })
+        .await;
+        let rpc = client(url, Duration::from_secs(5), 0);
+
+        let mint = rpc
+            .get_mint_account("Mint111")
+            .await
+            .expect("account")
+            .expect("mint");
+        assert_eq!(None, mint.mint_authority);
+        assert_eq!(Some("Freeze111".to_string()), mint.freeze_authority);
+        assert_eq!(1_000_000_000, mint.supply);
+
+        let supply = rpc.get_token_supply("Mint111").await.expect("supply");
+        assert_eq!(
+            TokenAmount {
+                amount: 1_000_000_000,
+                decimals: 6
+            },
+            supply
+        );
+        assert_eq!(1_000.0, supply.ui_amount());
+
+        let largest = rpc
+            .get_token_largest_accounts("Mint111")
+            .await
+            .expect("largest");
+        assert_eq!(400_000_000, largest[0].amount);
+        assert_eq!("Acct1", largest[0].address);
+
+        let accounts = rpc
+            .get_multiple_accounts(&["Acct1".to_string(), "Gone111".to_string()])
+            .await
+            .expect("accounts");
+        let token_account = accounts[0].as_ref().expect("token account");
+        assert_eq!(Some("Wallet111".to_string()), token_account.token_owner());
+        assert!(accounts[1].is_none());
+    }
+
+    #[tokio::test]
+    async fn decodes_transactions_and_signature_pages() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorded = seen.clone();
+        let url = mock_rpc(Duration::ZERO, move |_, request| {
+            recorded.lock().unwrap().push(request["params"].clone());
+            match request["method"].as_str() {
+                Some("getTransaction") if request["params"][0] == "unknown" => result(Value::Null),
+                Some("getTransaction") => result(json!({
+                    "slot": 42,
+                    "blockTime": 1_700_000_000,
+                    "meta": {
+                        "err": null,
+                        "fee": 5000,
+                        "preTokenBalances": [],
+                        "postTokenBalances": [{
+                            "accountIndex": 1,
+                            "mint": "Mint111",
+                            "owner": "Wallet111",
+                            "uiTokenAmount": {"amount": "2500", "decimals": 2, "uiAmount": 25.0}
+                        }]
+                    },
+                    "transaction": {}
+                })),
+                Some("getSignaturesForAddress") => result(json!([
+                    {"signature": "sig2", "slot": 42, "blockTime": null, "err": null},
+                    {"signature": "sig1", "slot": 41, "err": {"InstructionError": [0, "Custom"]}}
+                ])),
+                _ => (StatusCode::BAD_REQUEST, json!({})),
+            }
+        })
+        .await;
+        let rpc = client(url, Duration::from_secs(5), 0);
+
+        let tx = rpc
+            .get_transaction("sig2")
+            .await
+            .expect("transaction")
+            .expect("known transaction");
+        let meta = tx.meta.expect("meta");
+        assert_eq!(
+            (42, Some(1_700_000_000), 5000),
+            (tx.slot, tx.block_time, meta.fee)
+        );
+        assert_eq!(
+            25.0,
+            meta.post_token_balances[0].ui_token_amount.ui_amount()
+        );
+        assert!(
+            rpc.get_transaction("unknown")
+                .await
+                .expect("null result")
+                .is_none()
+        );
+
+        let page = rpc
+            .get_signatures_for_address("Wallet111", 2, Some("sig3"))
+            .await
+            .expect("signatures");
+        assert_eq!("sig2", page[0].signature);
+        assert!(page[0].err.is_none() && page[1].err.is_some());
+        let params = seen.lock().unwrap().last().cloned().expect("request");
+        assert_eq!(
+            json!({"limit": 2, "commitment": "confirmed", "before": "sig3"}),
+            params[1]
+        );
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors_but_not_rpc_errors() {
+        let url = mock_rpc(Duration::ZERO, |call, request| {
+            match request["method"].as_str() {
+                Some("getTokenSupply") if call < 2 => (StatusCode::SERVICE_UNAVAILABLE, json!({})),
+                Some("getTokenSupply") => result(json!({
+                    "context": {"slot": 1},
+                    "value": {"amount": "5", "decimals": 0}
+                })),
+                _ => (
+                    StatusCode::OK,
+                    json!({
+                        "jsonrpc": "2.0",
+                        "id": 1,
+                        "error": {"code": -32602, "message": "Invalid param"}
+                    }),
+                ),
+            }
+        })
+        .await;
+        let rpc = client(url, Duration::from_secs(5), 2);
+
+        let supply = rpc
+            .get_token_supply("Mint111")
+            .await
+            .expect("third attempt");
+        assert_eq!(5, supply.amount);
+
+        let err = rpc
+            .get_token_largest_accounts("bad")
+            .await
+            .expect_err("rpc error");
+        assert!(matches!(err, RpcError::Rpc { code: -32602, .. }));
+        assert_eq!("-32602", err.status_label());
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_timeouts() {
+        let url = mock_rpc(Duration::from_millis(500), |_, _| {
+            result(json!({"context": {"slot": 1}, "value": []}))
+        })
+        .await;
+        let rpc = client(url, Duration::from_millis(50), 1);
+
+        let err = rpc
+            .get_token_largest_accounts("Mint111")
+            .await
+            .expect_err("timeout");
+        assert_eq!("timeout", err.status_label());
+    }
+}
@@ -0,0 +1,298 @@
+use axum::{
+    Json, Router,
+    extract::State,
+    http::StatusCode,
+    routing::{get, post, put},
+};
+use axum_server::tls_rustls::RustlsConfig;
+use std::{
+    io,
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+use tracing::{error, info, warn};
+
+use crate::{
+    analyzer::Analyzer,
+    config::{ServerSettings, TlsSettings},
+    health::{self, Readiness},
+    helius::HeliusWebhook,
+    latency::LatencyTrace,
+    metrics::{self, Metrics},
+    performance::{self, PerformanceTracker},
+    profiles,
+    watch::FileStamps,
+};
+
+#[derive(Clone)]
+pub struct AppState {
+    pub analyzer: Analyzer,
+    pub readiness: Readiness,
+    pub metrics: Metrics,
+    pub performance: PerformanceTracker,
+    pub admin_token: Option<String>,
+}
+
+async fn webhook_handler(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<HeliusWebhook>,
+) -> StatusCode {
+    let trace = LatencyTrace::start(payload.timestamp);
+    state.metrics.webhook_received();
+
+    let Some(slot) = state.readiness.try_acquire() else {
+        state.metrics.event_skipped("queue_saturated");
+        warn!(
+            signature = payload.signature.as_str(),
+            reason = "queue_saturated",
+            "reject_event"
+        );
+        return StatusCode::SERVICE_UNAVAILABLE;
+    };
+
+    let analyzer = state.analyzer.clone();
+    tokio::spawn(async move {
+        let _slot = slot;
+        if let Err(err) = analyzer.process_event(payload, trace).await {
+            error!(error = ?err, "analyzer_error");
+        }
+    });
+
+    StatusCode::ACCEPTED
+}
+
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/webhook", post(webhook_handler))
+        .route("/healthz", get(health::healthz_handler))
+        .route("/readyz", get(health::readyz_handler))
+        .route("/version", get(health::version_handler))
+        .route("/metrics", get(metrics::metrics_handler))
+        .route("/profiles", get(profiles::list_profiles_handler))
+        .route("/profiles/active", put(profiles::switch_profile_handler))
+        .route("/stats", get(performance::stats_handler))
+        .with_state(state)
+}
+
+/// Time allowed for the `--healthcheck` request.
+const HEALTHCHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Requests `/healthz` from the listener `settings` describes, over HTTPS when TLS is on.
+///
+/// Used as the container health check, so the probe follows the port and scheme from
+/// the config file at `$CONFIG_PATH` as well as from the environment.
+pub async fn healthcheck(settings: &ServerSettings) -> Result<(), reqwest::Error> {
+    let mut addr = settings.addr;
+    if addr.ip().is_unspecified() {
+        addr.set_ip(match addr {
+            SocketAddr::V4(_) => Ipv4Addr::LOCALHOST.into(),
+            SocketAddr::V6(_) => Ipv6Addr::LOCALHOST.into(),
+        });
+    }
+    let scheme = if settings.tls.is_some() {
+        "https"
+    } else {
+        "http"
+    };
+    let client = reqwest::Client::builder()
+        .no_proxy()
+        // The certificate names the public host, not the loopback address probed here.
+        .danger_accept_invalid_certs(true)
+        .timeout(HEALTHCHECK_TIMEOUT)
+        .build()?;
+    client
+        .get(format!("{scheme}://{addr}/healthz"))
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+
+/// Serves `app` over plain HTTP, or HTTPS when TLS is configured.
+pub async fn serve(app: Router, settings: &ServerSettings) -> io::Result<()> {
+    match &settings.tls {
+        None => {
+            info!("listening on http://{}/webhook", settings.addr);
+            let listener = tokio::net::TcpListener::bind(settings.addr).await?;
+            axum::serve(listener, app).await
+        }
+        Some(tls) => {
+            let rustls_config = load_tls(tls).await?;
+            tokio::spawn(watch_certificates(rustls_config.clone(), tls.clone()));
+
+            info!("listening on https://{}/webhook", settings.addr);
+            serve_tls(
+                app,
+                settings.addr,
+                rustls_config,
+                axum_server::Handle::new(),
+            )
+            .await
+        }
+    }
+}
+
+async fn serve_tls(
+    app: Router,
+    addr: SocketAddr,
+    rustls_config: RustlsConfig,
+    handle: axum_server::Handle,
+) -> io::Result<()> {
+    axum_server::bind_rustls(addr, rustls_config)
+        .handle(handle)
+        .serve(app.into_make_service())
+        .await
+}
+
+async fn load_tls(tls: &TlsSettings) -> io::Result<RustlsConfig> {
+    // Several rustls providers can be compiled in; pin ours before building a config.
+    let _ = rustls::crypto::ring::default_provider().install_default();
+    RustlsConfig::from_pem_file(&tls.cert_path, &tls.key_path).await
+}
+
+/// Reloads the certificate and key whenever either file changes on disk.
+///
+/// A failed reload keeps serving the previous certificate.
+async fn watch_certificates(rustls_config: RustlsConfig, tls: TlsSettings) {
+    let mut stamps = FileStamps::new(vec![tls.cert_path.clone(), tls.key_path.clone()]);
+    let mut interval = tokio::time::interval(tls.reload_interval);
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+        if !stamps.changed() {
+            continue;
+        }
+
+        match rustls_config
+            .reload_from_pem_file(&tls.cert_path, &tls.key_path)
+            .await
+        {
+            Ok(()) => info!(cert_path = %tls.cert_path.display(), "tls_certificate_reloaded"),
+            Err(err) => error!(
+                cert_path = %tls.cert_path.display(),
+                error = ?err,
+                "tls_certificate_reload_failed"
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{healthcheck, load_tls, serve_tls, watch_certificates};
+    use crate::config::{ServerSettings, TlsSettings};
+    use axum::{Router, routing::get};
+    use std::{fs, net::SocketAddr, path::PathBuf, time::Duration};
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("{name}-{}", std::process::id()));
+            fs::create_dir_all(&path).expect("create temp dir");
+            Self(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn write_self_signed(tls: &TlsSettings) -> Vec<u8> {
+        let certified = rcgen::generate_simple_self_signed(vec!["localhost".to_string()])
+            .expect("self-signed certificate");
+        fs::write(&tls.cert_path, certified.cert.pem()).expect("write cert");
+        fs::write(&tls.key_path, certified.key_pair.serialize_pem()).expect("write key");
+        certified.cert.der().to_vec()
+    }
+
+    async fn peer_certificate(client: &reqwest::Client, addr: SocketAddr) -> Vec<u8> {
+        let response = client
+            .get(format!("https://localhost:{}/healthz", addr.port()))
+            .send()
+            .await
+            .expect("https request");
+        assert!(response.status().is_success());
+
+        response
+            .extensions()
+            .get::<reqwest::tls::TlsInfo>()
+            .and_then(|info| info.peer_certificate())
+            .expect("peer certificate")
+            .to_vec()
+    }
+
+    #[tokio::test]
+    async fn serves_https_and_reloads_changed_certificate() {
+        let dir = TempDir::new("solana-monitor-tls");
+        let tls = TlsSettings {
+            cert_path: dir.0.join("cert.pem"),
+            key_path: dir.0.join("key.pem"),
+            reload_interval: Duration::from_millis(50),
+        };
+        let first = write_self_signed(&tls);
+
+        let rustls_config = load_tls(&tls).await.expect("load tls");
+        let handle = axum_server::Handle::new();
+        let app = Router::new().route("/healthz", get(|| async { "ok" }));
+        tokio::spawn(serve_tls(
+            app,
+            SocketAddr::from(([127, 0, 0, 1], 0)),
+            rustls_config.clone(),
+            handle.clone(),
+        ));
+        tokio::spawn(watch_certificates(rustls_config, tls.clone()));
+        let addr = handle.listening().await.expect("server listening");
+
+        let client = reqwest::Client::builder()
+            .no_proxy()
+            .danger_accept_invalid_certs(true)
+            .tls_info(true)
+            .pool_max_idle_per_host(0)
+            .resolve("localhost", addr)
+            .build()
+            .expect("client");
+
+        assert_eq!(first, peer_certificate(&client, addr).await);
+
+        // Give the new files a distinct modification time before rewriting them.
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let second = write_self_signed(&tls);
+
+        let mut served = Vec::new();
+        for _ in 0..40 {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            served = peer_certificate(&client, addr).await;
+            if served == second {
+                break;
+            }
+        }
+        assert_eq!(second, served);
+        handle.shutdown();
+    }
+
+    #[tokio::test]
+    async fn healthcheck_probes_loopback_for_unspecified_address() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind");
+        let port = listener.local_addr().expect("addr").port();
+        let app = Router::new().route("/healthz", get(|| async { "ok" }));
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let settings = |port| ServerSettings {
+            addr: SocketAddr::from(([0, 0, 0, 0], port)),
+            tls: None,
+        };
+        healthcheck(&settings(port)).await.expect("healthy");
+
+        let closed = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let closed_port = closed.local_addr().expect("addr").port();
+        drop(closed);
+        assert!(healthcheck(&settings(closed_port)).await.is_err());
+    }
+}
@@ -1,7 +1,11 @@
-use reqwest::Client;
-use serde::Serialize;
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use thiserror::Error;
 
+/// Telegram's answer to an edit that would leave the text as it is.
+const NOT_MODIFIED: &str = "message is not modified";
+
 #[derive(Clone)]
 pub struct TelegramClient {
     http: Client,
@@ -23,7 +27,8 @@ impl TelegramClient {
         }
     }
 
-    pub async fn send_message(&self, chat_id: &str, text: &str) -> Result<(), TelegramError> {
+    /// Sends `text` to `chat_id` and returns the new message's ID.
+    pub async fn send_message(&self, chat_id: &str, text: &str) -> Result<i64, TelegramError> {
         let url = format!("{}/bot{}/sendMessage", self.base_url, self.bot_token);
         let payload = TelegramMessage {
             chat_id,
@@ -36,8 +41,159 @@ impl TelegramClient {
             return Err(TelegramError::HttpStatus(response.status()));
         }
 
+        let payload: SentResponse = response.json().await?;
+        match payload.result {
+            Some(sent) if payload.ok => Ok(sent.message_id),
+            _ => Err(TelegramError::NotOk),
+        }
+    }
+
+    /// Replaces the text of a message sent earlier.
+    ///
+    /// An edit to the text the message already shows succeeds; other refusals come back as
+    /// [`TelegramError::Rejected`] with Telegram's description and any `retry_after`.
+    pub async fn edit_message_text(
+        &self,
+        chat_id: &str,
+        message_id: i64,
+        text: &str,
+    ) -> Result<(), TelegramError> {
+        let url = format!("{}/bot{}/editMessageText", self.base_url, self.bot_token);
+        let payload = EditedMessage {
+            chat_id,
+            message_id,
+            text,
+            disable_web_page_preview: true,
+        };
+
+        let response = self.http.post(url).json(&payload).send().await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let refusal: ErrorResponse = response.json().await.unwrap_or_default();
+        let description = refusal.description.unwrap_or_default();
+        if status == StatusCode::BAD_REQUEST && description.contains(NOT_MODIFIED) {
+            return Ok(());
+        }
+        Err(TelegramError::Rejected {
+            status,
+            description,
+            retry_after: refusal
+                .parameters
+                .and_then(|parameters| parameters.retry_after)
+                .map(Duration::from_secs),
+        })
+    }
+
+    /// Calls `getMe` to confirm the bot token is accepted by the Telegram API.
+    pub async fn get_me(&self) -> Result<(), TelegramError> {
+        let url = format!("{}/bot{}/getMe", self.base_url, self.bot_token);
+        let response = self.http.get(url).send().await?;
+        if !response.status().is_success() {
+            return Err(TelegramError::HttpStatus(response.status()));
+        }
+
+        let payload: TelegramResponse = response.json().await?;
+        if !payload.ok {
+            return Err(TelegramError::NotOk);
+        }
+
         Ok(())
     }
+
+    /// Long-polls `getUpdates` for messages after `offset`, waiting up to `timeout_secs`.
+    pub async fn get_updates(
+        &self,
+        offset: i64,
+        timeout_secs: u64,
+    ) -> Result<Vec<Update>, TelegramError> {
+        let url = format!("{}/bot{}/getUpdates", self.base_url, self.bot_token);
+        let request = UpdatesRequest {
+            offset,
+            timeout: timeout_secs,
+            allowed_updates: &["message"],
+        };
+        let response = self.http.post(url).json(&request).send().await?;
+        if !response.status().is_success() {
+            return Err(TelegramError::HttpStatus(response.status()));
+        }
+
+        let payload: UpdatesResponse = response.json().await?;
+        if !payload.ok {
+            return Err(TelegramError::NotOk);
+        }
+
+        Ok(payload.result)
+    }
+}
+
+#[derive(Deserialize)]
+struct TelegramResponse {
+    ok: bool,
+}
+
+/// Body of a failed Bot API call.
+#[derive(Default, Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    parameters: Option<ErrorParameters>,
+}
+
+#[derive(Deserialize)]
+struct ErrorParameters {
+    /// Seconds to wait before the next request, on `429`.
+    #[serde(default)]
+    retry_after: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct SentResponse {
+    ok: bool,
+    #[serde(default)]
+    result: Option<SentMessage>,
+}
+
+#[derive(Deserialize)]
+struct SentMessage {
+    message_id: i64,
+}
+
+#[derive(Serialize)]
+struct UpdatesRequest<'a> {
+    offset: i64,
+    timeout: u64,
+    allowed_updates: &'a [&'a str],
+}
+
+#[derive(Deserialize)]
+struct UpdatesResponse {
+    ok: bool,
+    #[serde(default)]
+    result: Vec<Update>,
+}
+
+/// One incoming bot update; only plain messages are requested.
+#[derive(Debug, Deserialize)]
+pub struct Update {
+    pub update_id: i64,
+    #[serde(default)]
+    pub message: Option<IncomingMessage>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IncomingMessage {
+    pub chat: Chat,
+    #[serde(default)]
+    pub text: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Chat {
+    pub id: i64,
 }
 
 #[derive(Serialize)]
@@ -47,10 +203,120 @@ struct TelegramMessage<'a> {
     disable_web_page_preview: bool,
 }
 
+#[derive(Serialize)]
+struct EditedMessage<'a> {
+    chat_id: &'a str,
+    message_id: i64,
+    text: &'a str,
+    disable_web_page_preview: bool,
+}
+
 #[derive(Debug, Error)]
 pub enum TelegramError {
     #[error("telegram request failed: {0}")]
     Transport(#[from] reqwest::Error),
     #[error("telegram returned non-success status {0}")]
-    HttpStatus(reqwest::StatusCode),
+    HttpStatus(StatusCode),
+    #[error("telegram refused the request with status {status}: {description}")]
+    Rejected {
+        status: StatusCode,
+        description: String,
+        retry_after: Option<Duration>,
+    },
+    #[error("telegram responded with ok=false")]
+    NotOk,
+}
+
+impl TelegramError {
+    /// Short label for metrics: the HTTP status code, or a failure kind.
+    pub fn status_label(&self) -> String {
+        match self {
+            TelegramError::Transport(_) => "transport".to_string(),
+            TelegramError::HttpStatus(status) | TelegramError::Rejected { status, .. } => {
+                status.as_u16().to_string()
+            }
+            TelegramError::NotOk => "not_ok".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TelegramClient, TelegramError};
+    use axum::{Json, Router, http::StatusCode, routing::post};
+    use serde_json::{Value, json};
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
+
+    /// Answers `editMessageText` for bot `token` from `responses`, one per request in order.
+    async fn mock_edits(responses: Vec<(StatusCode, Value)>) -> TelegramClient {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let app = Router::new().route(
+            "/bottoken/editMessageText",
+            post(move || async move {
+                let (status, body) = responses[calls.fetch_add(1, Ordering::SeqCst)].clone();
+                (status, Json(body))
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock telegram");
+        let addr = listener.local_addr().expect("mock telegram addr");
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        TelegramClient::new("token".to_string(), format!("http://{addr}"))
+    }
+
+    fn refusal(code: u16, description: &str) -> Value {
+        json!({"ok": false, "error_code": code, "description": description})
+    }
+
+    #[tokio::test]
+    async fn edit_reports_refusals_with_retry_after() {
+        let telegram = mock_edits(vec![
+            (StatusCode::OK, json!({"ok": true, "result": true})),
+            (
+                StatusCode::BAD_REQUEST,
+                refusal(400, "Bad Request: message is not modified"),
+            ),
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                json!({
+                    "ok": false,
+                    "error_code": 429,
+                    "description": "Too Many Requests: retry after 35",
+                    "parameters": {"retry_after": 35}
+                }),
+            ),
+            (
+                StatusCode::BAD_REQUEST,
+                refusal(400, "Bad Request: message to edit not found"),
+            ),
+        ])
+        .await;
+        let edit = || telegram.edit_message_text("42", 7, "text");
+
+        edit().await.expect("edited");
+        edit().await.expect("unchanged text is not an error");
+        assert!(matches!(
+            edit().await,
+            Err(TelegramError::Rejected {
+                status: StatusCode::TOO_MANY_REQUESTS,
+                retry_after: Some(wait),
+                ..
+            }) if wait == Duration::from_secs(35)
+        ));
+        assert!(matches!(
+            edit().await,
+            Err(TelegramError::Rejected {
+                status: StatusCode::BAD_REQUEST,
+                retry_after: None,
+                ref description,
+            }) if description.contains("not found")
+        ));
+    }
 }
@@ -0,0 +1,386 @@
+use reqwest::StatusCode;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::Semaphore;
+use tracing::{info, warn};
+
+use crate::{
+    config::AlertUpdateSettings,
+    metrics::Metrics,
+    moralis::MoralisClient,
+    telegram::{TelegramClient, TelegramError},
+};
+
+/// Alerts kept updated at the same time; each one holds a task until its duration ends.
+const MAX_LIVE_ALERTS: usize = 1_000;
+
+/// Edits sent rule alerts with the latest price instead of sending new messages.
+#[derive(Clone)]
+pub struct AlertEditor {
+    telegram: TelegramClient,
+    moralis: MoralisClient,
+    metrics: Metrics,
+    chat_id: String,
+    live: Arc<Semaphore>,
+}
+
+/// A sent rule alert and what its edits are measured against.
+pub struct LiveAlert {
+    pub rule: String,
+    pub mint: String,
+    pub signature: String,
+    pub message_id: i64,
+    /// Text as sent; every edit appends the latest figures to it.
+    pub text: String,
+    pub price_usd: f64,
+    pub sent_at: Instant,
+}
+
+impl AlertEditor {
+    pub fn new(
+        telegram: TelegramClient,
+        moralis: MoralisClient,
+        metrics: Metrics,
+        chat_id: String,
+    ) -> Self {
+        Self {
+            telegram,
+            moralis,
+            metrics,
+            chat_id,
+            live: Arc::new(Semaphore::new(MAX_LIVE_ALERTS)),
+        }
+    }
+
+    /// Keeps `alert` updated in the background; returns `false`, without updating it,
+    /// when `MAX_LIVE_ALERTS` alerts are already being updated.
+    pub fn spawn(&self, alert: LiveAlert, settings: AlertUpdateSettings) -> bool {
+        let Ok(permit) = self.live.clone().try_acquire_owned() else {
+            return false;
+        };
+        let editor = self.clone();
+        tokio::spawn(async move {
+            let _permit = permit;
+            editor.keep_updated(alert, settings).await;
+        });
+        true
+    }
+
+    /// Re-prices the mint every interval and edits the alert until `settings.duration`
+    /// has passed since it was sent.
+    ///
+    /// A failed price lookup skips one edit. When Telegram rate-limits an edit, the next
+    /// one waits out its `retry_after` and the ticks missed meanwhile are dropped. A `400`
+    /// or `403` (the message was deleted, say) ends the updates.
+    async fn keep_updated(&self, alert: LiveAlert, settings: AlertUpdateSettings) {
+        let deadline = alert.sent_at + settings.duration;
+        let mut peak = alert.price_usd;
+        let mut due = alert.sent_at + settings.interval;
+        while due <= deadline {
+            tokio::time::sleep_until(due.into()).await;
+            let elapsed = due.duration_since(alert.sent_at);
+            due += settings.interval;
+
+            let Some(price) = self.fetch_price(&alert).await else {
+                continue;
+            };
+            peak = peak.max(price);
+            let text = format!(
+                "{}\n\n{}",
+                alert.text,
+                status_line(elapsed, alert.price_usd, price, peak)
+            );
+
+            let started = Instant::now();
+            let edited = self
+                .telegram
+                .edit_message_text(&self.chat_id, alert.message_id, &text)
+                .await;
+            self.metrics
+                .observe_provider_latency("telegram", started.elapsed());
+            if let Err(err) = edited {
+                self.metrics.provider_error("telegram", &err.status_label());
+                warn!(
+                    signature = alert.signature.as_str(),
+                    mint = alert.mint.as_str(),
+                    rule = alert.rule.as_str(),
+                    message_id = alert.message_id,
+                    error = ?err,
+                    source = "telegram",
+                    "alert_update_failed"
+                );
+                match err {
+                    TelegramError::Rejected { status, .. }
+                        if status == StatusCode::BAD_REQUEST || status == StatusCode::FORBIDDEN =>
+                    {
+                        return;
+                    }
+                    TelegramError::Rejected {
+                        retry_after: Some(wait),
+                        ..
+                    } => {
+                        tokio::time::sleep(wait).await;
+                        let resumed = tokio::time::Instant::now().into_std();
+                        while due < resumed {
+                            due += settings.interval;
+                        }
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+            info!(
+                signature = alert.signature.as_str(),
+                mint = alert.mint.as_str(),
+                rule = alert.rule.as_str(),
+                message_id = alert.message_id,
+                elapsed_secs = elapsed.as_secs(),
+                price_usd = price,
+                peak_usd = peak,
+                "alert_updated"
+            );
+        }
+    }
+
+    async fn fetch_price(&self, alert: &LiveAlert) -> Option<f64> {
+        let started = Instant::now();
+        let fetched = self.moralis.fetch_token_metrics(&alert.mint).await;
+        self.metrics
+            .observe_provider_latency("moralis", started.elapsed());
+        match fetched {
+            Ok(token) => token.price_usd,
+            Err(err) => {
+                self.metrics.provider_error("moralis", &err.status_label());
+                warn!(
+                    signature = alert.signature.as_str(),
+                    mint = alert.mint.as_str(),
+                    error = ?err,
+                    source = "moralis",
+                    lookup = "alert_update",
+                    "external_api_error"
+                );
+                None
+            }
+        }
+    }
+}
+
+/// e.g. `Update +5m: price 0.000120 (+20.0% since alert), peak 0.000150 (+50.0%)`.
+fn status_line(elapsed: Duration, alert_price: f64, price: f64, peak: f64) -> String {
+    let change = |to: f64| (to / alert_price - 1.0) * 100.0;
+    let secs = elapsed.as_secs();
+    let elapsed = if secs < 60 {
+        format!("{secs}s")
+    } else {
+        format!("{}m", secs / 60)
+    };
+    format!(
+        "Update +{elapsed}: price {price:.6} ({:+.1}% since alert), peak {peak:.6} ({:+.1}%)",
+        change(price),
+        change(peak)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AlertEditor, LiveAlert, status_line};
+    use crate::{
+        config::AlertUpdateSettings, metrics::Metrics, moralis::MoralisClient,
+        telegram::TelegramClient,
+    };
+    use axum::{
+        Json, Router,
+        extract::State,
+        http::StatusCode,
+        routing::{get, post},
+    };
+    use serde_json::{Value, json};
+    use std::{
+        sync::{
+            Arc, Mutex,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::{Duration, Instant},
+    };
+    use tokio::sync::Semaphore;
+
+    /// Mock Moralis and Telegram answering from fixed response lists, one per request.
+    struct Upstream {
+        prices: Vec<(StatusCode, Value)>,
+        price_calls: AtomicUsize,
+        edits: Vec<(StatusCode, Value)>,
+        /// Seconds since the test started and text of every edit attempted.
+        edited: Mutex<Vec<(u64, String)>>,
+        started: tokio::time::Instant,
+    }
+
+    async fn price(State(upstream): State<Arc<Upstream>>) -> (StatusCode, Json<Value>) {
+        let call = upstream.price_calls.fetch_add(1, Ordering::SeqCst);
+        let (status, body) = upstream.prices[call].clone();
+        (status, Json(body))
+    }
+
+    async fn edit(
+        State(upstream): State<Arc<Upstream>>,
+        Json(payload): Json<Value>,
+    ) -> (StatusCode, Json<Value>) {
+        let mut edited = upstream.edited.lock().expect("edits poisoned");
+        edited.push((
+            upstream.started.elapsed().as_secs(),
+            payload["text"].as_str().unwrap_or_default().to_string(),
+        ));
+        let (status, body) = upstream.edits[edited.len() - 1].clone();
+        (status, Json(body))
+    }
+
+    async fn editor(
+        prices: Vec<(StatusCode, Value)>,
+        edits: Vec<(StatusCode, Value)>,
+    ) -> (AlertEditor, Arc<Upstream>) {
+        let upstream = Arc::new(Upstream {
+            prices,
+            price_calls: AtomicUsize::new(0),
+            edits,
+            edited: Mutex::new(Vec::new()),
+            started: tokio::time::Instant::now(),
+        });
+        let app = Router::new()
+            .route("/tokens/:mint/price", get(price))
+            .route("/bottoken/editMessageText", post(edit))
+            .with_state(upstream.clone());
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .expect("bind mock upstream");
+        let url = format!("http://{}", listener.local_addr().expect("mock addr"));
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let editor = AlertEditor::new(
+            TelegramClient::new("token".to_string(), url.clone()),
+            MoralisClient::new("key".to_string(), url),
+            Metrics::new(),
+            "42".to_string(),
+        );
+        (editor, upstream)
+    }
+
+    fn alert() -> LiveAlert {
+        LiveAlert {
+            rule: "micro".to_string(),
+            mint: "Mint111".to_string(),
+            signature: "sig".to_string(),
+            message_id: 7,
+            text: "alert".to_string(),
+            price_usd: 1.0,
+            sent_at: Instant::now(),
+        }
+    }
+
+    fn settings(interval_secs: u64, duration_secs: u64) -> AlertUpdateSettings {
+        AlertUpdateSettings {
+            interval: Duration::from_secs(interval_secs),
+            duration: Duration::from_secs(duration_secs),
+        }
+    }
+
+    fn priced(usd: f64) -> (StatusCode, Value) {
+        (StatusCode::OK, json!({"usdPrice": usd}))
+    }
+
+    fn edited() -> (StatusCode, Value) {
+        (StatusCode::OK, json!({"ok": true, "result": true}))
+    }
+
+    fn edit_times(upstream: &Upstream) -> Vec<u64> {
+        let edited = upstream.edited.lock().expect("edits poisoned");
+        edited.iter().map(|(secs, _)| *secs).collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn edits_until_deadline_and_skips_failed_price() {
+        let (editor, upstream) = editor(
+            vec![
+                priced(1.5),
+                (StatusCode::SERVICE_UNAVAILABLE, json!({})),
+                priced(1.2),
+            ],
+            vec![edited(), edited()],
+        )
+        .await;
+
+        editor.keep_updated(alert(), settings(60, 180)).await;
+
+        assert_eq!(3, upstream.price_calls.load(Ordering::SeqCst));
+        let edited = upstream.edited.lock().expect("edits poisoned");
+        let texts: Vec<&str> = edited.iter().map(|(_, text)| text.as_str()).collect();
+        assert_eq!(
+            vec![
+                "alert\n\nUpdate +1m: price 1.500000 (+50.0% since alert), peak 1.500000 (+50.0%)",
+                "alert\n\nUpdate +3m: price 1.200000 (+20.0% since alert), peak 1.500000 (+50.0%)",
+            ],
+            texts
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn waits_out_rate_limit_and_stops_when_message_is_gone() {
+        let (editor, upstream) = editor(
+            vec![priced(1.0); 10],
+            vec![
+                edited(),
+                (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    json!({
+                        "ok": false,
+                        "description": "Too Many Requests: retry after 90",
+                        "parameters": {"retry_after": 90}
+                    }),
+                ),
+                edited(),
+                (
+                    StatusCode::BAD_REQUEST,
+                    json!({"ok": false, "description": "Bad Request: message to edit not found"}),
+                ),
+            ],
+        )
+        .await;
+
+        editor.keep_updated(alert(), settings(60, 3_600)).await;
+
+        // Mock round trips let the paused clock jump ahead, so only lower bounds are exact.
+        let times = edit_times(&upstream);
+        assert_eq!(4, times.len());
+        assert_eq!(4, upstream.price_calls.load(Ordering::SeqCst));
+        assert!(times[2] - times[1] >= 90);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn drops_alerts_beyond_the_live_limit() {
+        let unreachable = "http://127.0.0.1:9".to_string();
+        let editor = AlertEditor {
+            live: Arc::new(Semaphore::new(1)),
+            ..AlertEditor::new(
+                TelegramClient::new("token".to_string(), unreachable.clone()),
+                MoralisClient::new("key".to_string(), unreachable),
+                Metrics::new(),
+                "42".to_string(),
+            )
+        };
+
+        assert!(editor.spawn(alert(), settings(60, 3_600)));
+        assert!(!editor.spawn(alert(), settings(60, 3_600)));
+    }
+
+    #[test]
+    fn status_line_shows_change_since_alert_and_peak() {
+        assert_eq!(
+            "Update +5m: price 0.000120 (+20.0% since alert), peak 0.000150 (+50.0%)",
+            status_line(Duration::from_secs(300), 0.0001, 0.00012, 0.00015)
+        );
+        assert_eq!(
+            "Update +30s: price 0.800000 (-20.0% since alert), peak 1.000000 (+0.0%)",
+            status_line(Duration::from_secs(30), 1.0, 0.8, 1.0)
+        );
+    }
+}
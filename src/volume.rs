@@ -0,0 +1,150 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// How often mints without recent trades are dropped.
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Recent swap sizes per mint, in tokens, for rolling volume and spike detection.
+///
+/// Amounts are kept in token units and converted at the current price, so a
+/// spike ratio reflects trading activity rather than price movement.
+#[derive(Clone)]
+pub struct TradeVolumes {
+    inner: Arc<Mutex<Trades>>,
+}
+
+struct Trades {
+    retention: Duration,
+    by_mint: HashMap<String, VecDeque<(Instant, f64)>>,
+    last_sweep: Instant,
+}
+
+/// Trading in one window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowVolume {
+    pub tokens: f64,
+    pub trades: usize,
+}
+
+/// Volume in the latest window compared to the trailing baseline rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spike {
+    pub recent: WindowVolume,
+    /// Baseline volume scaled to one window's length.
+    pub baseline_tokens: f64,
+    /// `recent.tokens / baseline_tokens`; `None` when there was no baseline volume.
+    pub ratio: Option<f64>,
+}
+
+impl TradeVolumes {
+    /// Keeps trades for `retention`, which should cover the longest window plus baseline.
+    pub fn new(retention: Duration) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Trades {
+                retention,
+                by_mint: HashMap::new(),
+                last_sweep: Instant::now(),
+            })),
+        }
+    }
+
+    pub fn set_retention(&self, retention: Duration) {
+        self.inner.lock().expect("trade volumes poisoned").retention = retention;
+    }
+
+    pub fn record(&self, mint: &str, tokens: f64) {
+        self.record_at(mint, tokens, Instant::now());
+    }
+
+    /// Volume in the last `window` against the `baseline` period just before it.
+    pub fn spike(&self, mint: &str, window: Duration, baseline: Duration) -> Spike {
+        self.spike_at(mint, window, baseline, Instant::now())
+    }
+
+    fn record_at(&self, mint: &str, tokens: f64, now: Instant) {
+        if !tokens.is_finite() || tokens <= 0.0 {
+            return;
+        }
+
+        let mut inner = self.inner.lock().expect("trade volumes poisoned");
+        let retention = inner.retention;
+        if retention.is_zero() {
+            return;
+        }
+        if now.duration_since(inner.last_sweep) >= SWEEP_INTERVAL {
+            inner.by_mint.retain(|_, trades| {
+                trades
+                    .back()
+                    .is_some_and(|(at, _)| now.duration_since(*at) < retention)
+            });
+            inner.last_sweep = now;
+        }
+
+        let trades = inner.by_mint.entry(mint.to_string()).or_default();
+        while trades
+            .front()
+            .is_some_and(|(at, _)| now.duration_since(*at) >= retention)
+        {
+            trades.pop_front();
+        }
+        trades.push_back((now, tokens));
+    }
+
+    fn spike_at(&self, mint: &str, window: Duration, baseline: Duration, now: Instant) -> Spike {
+        let inner = self.inner.lock().expect("trade volumes poisoned");
+        let mut recent = WindowVolume {
+            tokens: 0.0,
+            trades: 0,
+        };
+        let mut baseline_total = 0.0;
+        for (at, tokens) in inner.by_mint.get(mint).into_iter().flatten() {
+            let age = now.duration_since(*at);
+            if age <= window {
+                recent.tokens += tokens;
+                recent.trades += 1;
+            } else if age <= window + baseline {
+                baseline_total += tokens;
+            }
+        }
+
+        let baseline_tokens = baseline_total * window.as_secs_f64() / baseline.as_secs_f64();
+        Spike {
+            recent,
+            baseline_tokens,
+            ratio: (baseline_tokens > 0.0).then(|| recent.tokens / baseline_tokens),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TradeVolumes;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn compares_recent_window_to_scaled_baseline() {
+        let volumes = TradeVolumes::new(Duration::from_secs(660));
+        let start = Instant::now();
+        let window = Duration::from_secs(60);
+        let baseline = Duration::from_secs(600);
+
+        // 100 tokens per minute for ten minutes, then 1500 tokens in the last minute.
+        for minute in 0..10 {
+            volumes.record_at("mint", 100.0, start + Duration::from_secs(minute * 60));
+        }
+        volumes.record_at("mint", 1_000.0, start + Duration::from_secs(630));
+        volumes.record_at("mint", 500.0, start + Duration::from_secs(650));
+
+        let spike = volumes.spike_at("mint", window, baseline, start + Duration::from_secs(660));
+        assert_eq!(2, spike.recent.trades);
+        assert_eq!(1_500.0, spike.recent.tokens);
+        assert!((spike.baseline_tokens - 100.0).abs() < 1e-9);
+        assert!((spike.ratio.expect("ratio") - 15.0).abs() < 1e-9);
+
+        let quiet = volumes.spike_at("other", window, baseline, start);
+        assert_eq!(None, quiet.ratio);
+    }
+}
@@ -0,0 +1,36 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+/// Detects changes to a set of files by polling their modification time and size.
+///
+/// Polling keeps working on bind-mounted Docker volumes and Kubernetes secret
+/// mounts, where inotify events are unreliable.
+pub struct FileStamps {
+    paths: Vec<PathBuf>,
+    last: Vec<Option<(SystemTime, u64)>>,
+}
+
+impl FileStamps {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let last = paths.iter().map(|path| stamp(path)).collect();
+        Self { paths, last }
+    }
+
+    /// Returns `true` if any file changed since the previous call (or construction).
+    pub fn changed(&mut self) -> bool {
+        let current: Vec<_> = self.paths.iter().map(|path| stamp(path)).collect();
+        if current == self.last {
+            return false;
+        }
+        self.last = current;
+        true
+    }
+}
+
+fn stamp(path: &Path) -> Option<(SystemTime, u64)> {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
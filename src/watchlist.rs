@@ -0,0 +1,371 @@
+use std::{
+    collections::BTreeSet,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+};
+use thiserror::Error;
+
+use crate::{
+    config::MintListSettings,
+    helius::{HeliusWebhook, decode_base58},
+};
+
+/// Whether `value` is a base58 Solana account address (32 bytes).
+pub fn is_wallet_address(value: &str) -> bool {
+    (32..=44).contains(&value.len()) && decode_base58(value).is_some_and(|bytes| bytes.len() == 32)
+}
+
+/// A managed set of addresses: watched wallets, or watched and ignored mints.
+///
+/// Configured addresses come from the config file and change on reload; addresses added
+/// with bot commands are kept separately and persisted to the store file, if any.
+#[derive(Clone)]
+pub struct Watchlist {
+    inner: Arc<RwLock<Entries>>,
+    store_path: Option<PathBuf>,
+}
+
+struct Entries {
+    configured: BTreeSet<String>,
+    managed: BTreeSet<String>,
+}
+
+/// Where a list entry came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalletSource {
+    Config,
+    Command,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum WatchOutcome {
+    Added,
+    AlreadyWatched,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum UnwatchOutcome {
+    Removed,
+    NotWatched,
+    /// Configured wallets can only be removed from the config file.
+    Configured,
+}
+
+/// How a watched wallet took part in a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalletRole {
+    Signer,
+    Sender,
+    Receiver,
+}
+
+impl WalletRole {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WalletRole::Signer => "signer",
+            WalletRole::Sender => "sender",
+            WalletRole::Receiver => "receiver",
+        }
+    }
+}
+
+/// One watched wallet's part in a transaction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WalletActivity {
+    pub wallet: String,
+    pub role: WalletRole,
+    pub mint: Option<String>,
+    pub tokens: Option<f64>,
+}
+
+impl Watchlist {
+    /// Seeds the list from config and the store file; a missing store file is empty.
+    pub fn load(
+        configured: &[String],
+        store_path: Option<PathBuf>,
+    ) -> Result<Self, WatchlistError> {
+        let managed = match &store_path {
+            Some(path) => read_store(path)?,
+            None => BTreeSet::new(),
+        };
+        Ok(Self {
+            inner: Arc::new(RwLock::new(Entries {
+                configured: configured.iter().cloned().collect(),
+                managed,
+            })),
+            store_path,
+        })
+    }
+
+    pub fn set_configured(&self, configured: &[String]) {
+        self.inner.write().expect("watchlist poisoned").configured =
+            configured.iter().cloned().collect();
+    }
+
+    pub fn contains(&self, wallet: &str) -> bool {
+        let inner = self.inner.read().expect("watchlist poisoned");
+        inner.configured.contains(wallet) || inner.managed.contains(wallet)
+    }
+
+    /// Every watched wallet in address order; configured entries win over duplicates.
+    pub fn list(&self) -> Vec<(String, WalletSource)> {
+        let inner = self.inner.read().expect("watchlist poisoned");
+        let mut wallets: Vec<(String, WalletSource)> = inner
+            .configured
+            .iter()
+            .map(|wallet| (wallet.clone(), WalletSource::Config))
+            .chain(
+                inner
+                    .managed
+                    .difference(&inner.configured)
+                    .map(|wallet| (wallet.clone(), WalletSource::Command)),
+            )
+            .collect();
+        wallets.sort_by(|a, b| a.0.cmp(&b.0));
+        wallets
+    }
+
+    pub fn watch(&self, wallet: &str) -> Result<WatchOutcome, WatchlistError> {
+        if !is_wallet_address(wallet) {
+            return Err(WatchlistError::InvalidAddress(wallet.to_string()));
+        }
+        let mut inner = self.inner.write().expect("watchlist poisoned");
+        if inner.configured.contains(wallet) || inner.managed.contains(wallet) {
+            return Ok(WatchOutcome::AlreadyWatched);
+        }
+        inner.managed.insert(wallet.to_string());
+        if let Err(err) = self.persist(&inner.managed) {
+            inner.managed.remove(wallet);
+            return Err(err);
+        }
+        Ok(WatchOutcome::Added)
+    }
+
+    pub fn unwatch(&self, wallet: &str) -> Result<UnwatchOutcome, WatchlistError> {
+        let mut inner = self.inner.write().expect("watchlist poisoned");
+        if inner.configured.contains(wallet) {
+            return Ok(UnwatchOutcome::Configured);
+        }
+        if !inner.managed.remove(wallet) {
+            return Ok(UnwatchOutcome::NotWatched);
+        }
+        if let Err(err) = self.persist(&inner.managed) {
+            inner.managed.insert(wallet.to_string());
+            return Err(err);
+        }
+        Ok(UnwatchOutcome::Removed)
+    }
+
+    /// Watched wallets in `event`: the fee payer as signer and every transfer leg.
+    pub fn activity(&self, event: &HeliusWebhook) -> Vec<WalletActivity> {
+        let mut found = Vec::new();
+        for transfer in &event.events.token_transfers {
+            for (account, role) in [
+                (&transfer.from_user_account, WalletRole::Sender),
+                (&transfer.to_user_account, WalletRole::Receiver),
+            ] {
+                if let Some(wallet) = account.as_deref()
+                    && self.contains(wallet)
+                {
+                    found.push(WalletActivity {
+                        wallet: wallet.to_string(),
+                        role,
+                        mint: Some(transfer.mint.clone()),
+                        tokens: transfer.token_amount,
+                    });
+                }
+            }
+        }
+        // A signer that moved no tokens itself still touched the transaction's token.
+        if let Some(signer) = event.fee_payer.as_deref()
+            && self.contains(signer)
+            && !found.iter().any(|activity| activity.wallet == signer)
+        {
+            found.push(WalletActivity {
+                wallet: signer.to_string(),
+                role: WalletRole::Signer,
+                mint: event.primary_mint().map(str::to_string),
+                tokens: None,
+            });
+        }
+        found
+    }
+
+    fn persist(&self, managed: &BTreeSet<String>) -> Result<(), WatchlistError> {
+        let Some(path) = &self.store_path else {
+            return Ok(());
+        };
+        let mut contents: String = managed.iter().map(|wallet| format!("{wallet}\n")).collect();
+        if contents.is_empty() {
+            contents.push('\n');
+        }
+        // Write then rename so a crash never leaves a truncated list behind.
+        let staging = path.with_extension("tmp");
+        fs::write(&staging, contents)
+            .and_then(|()| fs::rename(&staging, path))
+            .map_err(|source| WatchlistError::Store {
+                path: path.clone(),
+                source,
+            })
+    }
+}
+
+/// Mints that always pass the rule filters and mints that are never looked at.
+#[derive(Clone)]
+pub struct MintLists {
+    pub watched: Watchlist,
+    pub ignored: Watchlist,
+}
+
+impl MintLists {
+    pub fn load(settings: &MintListSettings) -> Result<Self, WatchlistError> {
+        Ok(Self {
+            watched: Watchlist::load(&settings.watched, settings.watched_store_path.clone())?,
+            ignored: Watchlist::load(&settings.ignored, settings.ignored_store_path.clone())?,
+        })
+    }
+
+    pub fn set_configured(&self, settings: &MintListSettings) {
+        self.watched.set_configured(&settings.watched);
+        self.ignored.set_configured(&settings.ignored);
+    }
+
+    /// Ignoring wins when a mint is on both lists.
+    pub fn status(&self, mint: &str) -> MintStatus {
+        if self.ignored.contains(mint) {
+            MintStatus::Ignored
+        } else if self.watched.contains(mint) {
+            MintStatus::Watched
+        } else {
+            MintStatus::Unlisted
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MintStatus {
+    /// On neither list; the rules decide.
+    Unlisted,
+    Watched,
+    Ignored,
+}
+
+/// One address per line; blank lines and `#` comments are skipped.
+fn read_store(path: &Path) -> Result<BTreeSet<String>, WatchlistError> {
+    let raw = match fs::read_to_string(path) {
+        Ok(raw) => raw,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(BTreeSet::new()),
+        Err(source) => {
+            return Err(WatchlistError::Store {
+                path: path.to_path_buf(),
+                source,
+            });
+        }
+    };
+    let mut wallets = BTreeSet::new();
+    for line in raw.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if !is_wallet_address(line) {
+            return Err(WatchlistError::InvalidAddress(line.to_string()));
+        }
+        wallets.insert(line.to_string());
+    }
+    Ok(wallets)
+}
+
+#[derive(Debug, Error)]
+pub enum WatchlistError {
+    #[error("{0:?} is not a valid Solana address")]
+    InvalidAddress(String),
+    #[error("failed to update watchlist file {}", path.display())]
+    Store {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UnwatchOutcome, WalletRole, WalletSource, WatchOutcome, Watchlist};
+    use crate::helius::HeliusWebhook;
+
+    const WHALE: &str = "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM";
+    const FRIEND: &str = "7xKXtg2CW87d97TXJSDpbD5jBkheTqA83TZRuJosgAsU";
+
+    #[test]
+    fn managed_wallets_persist_and_configured_ones_stay() {
+        let dir = std::env::temp_dir().join(format!("watchlist-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("temp dir");
+        let store = dir.join("wallets.txt");
+        let _ = std::fs::remove_file(&store);
+
+        let watchlist = Watchlist::load(&[WHALE.to_string()], Some(store.clone())).expect("load");
+        assert_eq!(WatchOutcome::Added, watchlist.watch(FRIEND).expect("watch"));
+        assert_eq!(
+            WatchOutcome::AlreadyWatched,
+            watchlist.watch(WHALE).expect("watch")
+        );
+        assert!(watchlist.watch("not-an-address").is_err());
+        assert_eq!(
+            UnwatchOutcome::Configured,
+            watchlist.unwatch(WHALE).expect("unwatch")
+        );
+
+        let reloaded = Watchlist::load(&[], Some(store.clone())).expect("reload");
+        assert_eq!(
+            vec![(FRIEND.to_string(), WalletSource::Command)],
+            reloaded.list()
+        );
+        assert_eq!(
+            UnwatchOutcome::Removed,
+            reloaded.unwatch(FRIEND).expect("unwatch")
+        );
+        assert!(
+            Watchlist::load(&[], Some(store))
+                .expect("load")
+                .list()
+                .is_empty()
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn finds_watched_signer_and_counterparties() {
+        let event: HeliusWebhook = serde_json::from_value(serde_json::json!({
+            "signature": "sig",
+            "type": "TRANSFER",
+            "feePayer": WHALE,
+            "events": {"tokenTransfers": [
+                {"mint": "MintA", "fromUserAccount": "someone", "toUserAccount": FRIEND, "tokenAmount": 10.0},
+                {"mint": "MintB", "fromUserAccount": "someone", "toUserAccount": "other", "tokenAmount": 5.0}
+            ]}
+        }))
+        .expect("event");
+        let watchlist =
+            Watchlist::load(&[WHALE.to_string(), FRIEND.to_string()], None).expect("load");
+
+        let activity = watchlist.activity(&event);
+        assert_eq!(2, activity.len());
+        assert_eq!(
+            (FRIEND, WalletRole::Receiver, Some("MintA"), Some(10.0)),
+            (
+                activity[0].wallet.as_str(),
+                activity[0].role,
+                activity[0].mint.as_deref(),
+                activity[0].tokens
+            )
+        );
+        assert_eq!(
+            (WHALE, WalletRole::Signer, Some("MintA")),
+            (
+                activity[1].wallet.as_str(),
+                activity[1].role,
+                activity[1].mint.as_deref()
+            )
+        );
+    }
+}